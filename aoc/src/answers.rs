@@ -0,0 +1,123 @@
+use nom::IResult;
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{alphanumeric1, digit1, space1, u8};
+use nom::combinator::{map, opt, recognize, value};
+use nom::sequence::{pair, tuple};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::days::{repo_root, InputKind};
+
+// Answers we've recorded as correct, keyed by (day, part, input kind).
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    recorded: HashMap<(String, u8, InputKind), String>,
+}
+
+// How a computed answer compares against the recorded one.
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unrecorded,
+}
+
+fn input_kind(input: &str) -> IResult<&str, InputKind> {
+    alt((
+        value(InputKind::Example, tag("example")),
+        value(InputKind::Real, tag("real")),
+    ))(input)
+}
+
+pub fn answer(input: &str) -> IResult<&str, String> {
+    map(recognize(pair(opt(tag("-")), digit1)), String::from)(input)
+}
+
+// dayone 2 example 281
+fn answer_line(input: &str) -> IResult<&str, ((String, u8, InputKind), String)> {
+    let (input, (day, _, part, _, kind, _, answer)) =
+      tuple((alphanumeric1, space1, u8, space1, input_kind, space1, answer))(input)?;
+    Ok((input, ((String::from(day), part, kind), answer)))
+}
+
+pub fn is_comment(line: &str) -> bool {
+    line.is_empty() || line.starts_with('#')
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Answers, String> {
+        let mut recorded = HashMap::new();
+        for (idx, line) in input.lines().map(str::trim).enumerate() {
+            if is_comment(line) {
+                continue;
+            }
+            let (rest, (key, answer)) = answer_line(line)
+              .map_err(|e| format!("line {}: {e}", idx + 1))?;
+            // Allow trailing comments after the answer.
+            if !is_comment(rest.trim()) {
+                return Err(format!("line {}: unexpected trailing input '{rest}'", idx + 1));
+            }
+            recorded.insert(key, answer);
+        }
+        Ok(Answers { recorded })
+    }
+
+    pub fn path() -> PathBuf {
+        repo_root().join("aoc").join("answers.txt")
+    }
+
+    // Loads the recorded answers, treating a missing file as no answers.
+    pub fn load() -> Result<Answers, String> {
+        match fs::read_to_string(Answers::path()) {
+            Ok(contents) => Answers::parse(&contents),
+            Err(_) => Ok(Answers::default()),
+        }
+    }
+
+    pub fn check(&self, day: &str, part: u8, kind: InputKind, answer: &str) -> Verdict {
+        Verdict::of(self.recorded.get(&(String::from(day), part, kind)), answer)
+    }
+}
+
+impl Verdict {
+    // How an answer compares against the one expected, if there is one.
+    pub fn of(expected: Option<&String>, answer: &str) -> Verdict {
+        match expected {
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong { expected: expected.clone() },
+            None => Verdict::Unrecorded,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::*;
+
+    #[test]
+    fn test_answer_line() {
+        assert_eq!(answer_line("dayone 2 example 281"),
+          Ok(("", ((String::from("dayone"), 2, InputKind::Example), String::from("281")))));
+        assert_eq!(answer_line("day9 1 real -5 # odd one"),
+          Ok((" # odd one", ((String::from("day9"), 1, InputKind::Real), String::from("-5")))));
+        assert!(answer_line("day9 1 sample 5").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("# header\n\
+          \n\
+          day2 2 example 2286\n\
+          day2 2 real 1234").unwrap();
+        assert_eq!(answers.check("day2", 2, InputKind::Example, "2286"), Verdict::Correct);
+        assert_eq!(answers.check("day2", 2, InputKind::Real, "1"), Verdict::Wrong { expected: String::from("1234") });
+        assert_eq!(answers.check("day2", 1, InputKind::Real, "1"), Verdict::Unrecorded);
+    }
+
+    #[test]
+    fn test_bad_line() {
+        assert!(Answers::parse("day2 2 example 2286 extra").is_err());
+    }
+}
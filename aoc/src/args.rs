@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+// Command line arguments, split into positionals and `--flag [value]` options.
+#[derive(Debug, PartialEq)]
+pub struct Args {
+    pub positional: Vec<String>,
+    options: HashMap<String, Option<String>>,
+}
+
+impl Args {
+    // Parses arguments, where the flags listed in `valued` take a value.
+    pub fn parse(args: &[String], valued: &[&str]) -> Result<Args, String> {
+        let mut positional = Vec::new();
+        let mut options = HashMap::new();
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.strip_prefix("--") {
+                Some(flag) if valued.contains(&flag) => {
+                    let Some(value) = iter.next() else {
+                        return Err(format!("--{flag} needs a value"));
+                    };
+                    options.insert(String::from(flag), Some(value.clone()));
+                },
+                Some(flag) => {
+                    options.insert(String::from(flag), None);
+                },
+                None => positional.push(arg.clone()),
+            }
+        }
+        Ok(Args { positional, options })
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.options.get(name).and_then(|v| v.as_deref())
+    }
+
+    // Fails on any flag not in `known`, to catch typos.
+    pub fn expect_only(&self, known: &[&str]) -> Result<(), String> {
+        match self.options.keys().find(|flag| !known.contains(&flag.as_str())) {
+            Some(flag) => Err(format!("Unknown option --{flag}")),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::args::*;
+
+    fn strings(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| String::from(*s)).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let args = Args::parse(&strings(&["day5", "--example", "2", "--input", "foo.txt"]), &["input"]).unwrap();
+        assert_eq!(args.positional, strings(&["day5", "2"]));
+        assert!(args.flag("example"));
+        assert_eq!(args.value("input"), Some("foo.txt"));
+        assert_eq!(args.value("example"), None);
+        assert!(args.expect_only(&["example", "input"]).is_ok());
+        assert!(args.expect_only(&["input"]).is_err());
+    }
+
+    #[test]
+    fn test_missing_value() {
+        assert!(Args::parse(&strings(&["--input"]), &["input"]).is_err());
+    }
+}
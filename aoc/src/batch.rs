@@ -0,0 +1,177 @@
+use nom::IResult;
+use nom::character::complete::{space1, u8};
+use nom::sequence::tuple;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::answers::{self, Verdict};
+use crate::days::{Day, Part};
+use crate::runner::{self, RunResult, RunStatus};
+
+// Runs one day over a directory of everyone's inputs, so we can cross-check
+// our answers. Next to each input there can be a sidecar file of the answers
+// its owner got, e.g. `alice.txt` and `alice.answers`, holding a line per
+// part like `2 54321`.
+
+// The extension of the answers file next to an input.
+const SIDECAR: &str = "answers";
+
+// How one part did on one input.
+pub struct BatchRow {
+    pub file: String,
+    pub part: &'static Part,
+    pub result: Result<RunResult, String>,
+    pub expected: Option<String>,
+}
+
+impl BatchRow {
+    pub fn status(&self) -> Result<RunStatus, String> {
+        self.result.as_ref()
+          .map(|result| result.status_against(self.expected.as_ref()))
+          .map_err(String::clone)
+    }
+
+    // Whether the run went wrong, or disagreed with the sidecar.
+    pub fn failed(&self) -> bool {
+        !matches!(self.status(), Ok(RunStatus::Answered(Verdict::Correct | Verdict::Unrecorded)))
+    }
+}
+
+// 2 54321
+fn sidecar_line(input: &str) -> IResult<&str, (u8, String)> {
+    let (input, (part, _, answer)) = tuple((u8, space1, answers::answer))(input)?;
+    Ok((input, (part, answer)))
+}
+
+// The answers in a sidecar file, by part.
+pub fn parse_sidecar(input: &str) -> Result<HashMap<u8, String>, String> {
+    let mut expected = HashMap::new();
+    for (idx, line) in input.lines().map(str::trim).enumerate() {
+        if answers::is_comment(line) {
+            continue;
+        }
+        let (rest, (part, answer)) = sidecar_line(line).map_err(|e| format!("line {}: {e}", idx + 1))?;
+        if !answers::is_comment(rest.trim()) {
+            return Err(format!("line {}: unexpected trailing input '{rest}'", idx + 1));
+        }
+        expected.insert(part, answer);
+    }
+    Ok(expected)
+}
+
+// Every input in a directory, in name order, leaving out sidecars and
+// hidden files.
+pub fn inputs(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("Unable to read {}: {e}", dir.display()))?;
+    let mut inputs: Vec<PathBuf> =
+      entries.filter_map(|entry| entry.ok().map(|entry| entry.path()))
+      .filter(|path| path.is_file())
+      .filter(|path| path.extension().is_none_or(|extension| extension != SIDECAR))
+      .filter(|path| !path.file_name().unwrap().to_string_lossy().starts_with('.'))
+      .collect();
+    inputs.sort();
+    Ok(inputs)
+}
+
+fn sidecar(input: &Path) -> Result<HashMap<u8, String>, String> {
+    let path = input.with_extension(SIDECAR);
+    match fs::read_to_string(&path) {
+        Ok(contents) => parse_sidecar(&contents).map_err(|e| format!("{}: {e}", path.display())),
+        Err(_) => Ok(HashMap::new()),
+    }
+}
+
+// Runs every part of `day` on every input in `dir`, each in a child process.
+pub fn run(day: &'static Day, dir: &Path, budget: Duration) -> Result<Vec<BatchRow>, String> {
+    let mut rows = Vec::new();
+    for input in inputs(dir)? {
+        let mut expected = sidecar(&input)?;
+        let file = input.file_name().unwrap().to_string_lossy().into_owned();
+        for part in day.parts {
+            let result = runner::run_isolated(day, part, &input, budget);
+            rows.push(BatchRow { file: file.clone(), part, result, expected: expected.remove(&part.number) });
+        }
+    }
+    Ok(rows)
+}
+
+// The rows as a table of file, part, answer, time and status.
+pub fn table(rows: &[BatchRow]) -> String {
+    let cells: Vec<[String; 5]> = rows.iter().map(|row| {
+        let (answer, time) = match &row.result {
+            Ok(result) => (
+                result.answer.clone().unwrap_or_default(),
+                result.answer.as_ref().map(|_| format!("{:.3}ms", result.elapsed.as_secs_f64() * 1000.0)).unwrap_or_default(),
+            ),
+            Err(_) => (String::new(), String::new()),
+        };
+        let status = match row.status() {
+            Ok(status) => status.to_string(),
+            Err(message) => message,
+        };
+        [row.file.clone(), row.part.number.to_string(), answer, time, status]
+    }).collect();
+    let header = [String::from("file"), String::from("part"), String::from("answer"), String::from("time"), String::from("status")];
+    let widths: Vec<usize> = (0..5).map(|col| {
+        cells.iter().chain([&header]).map(|row| row[col].len()).max().unwrap()
+    }).collect();
+    [&header].into_iter().chain(&cells).map(|row| {
+        let line = format!("{:w0$}  {:>w1$}  {:w2$}  {:>w3$}  {}",
+          row[0], row[1], row[2], row[3], row[4], w0 = widths[0], w1 = widths[1], w2 = widths[2], w3 = widths[3]);
+        String::from(line.trim_end()) + "\n"
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::batch::*;
+    use crate::days;
+    use std::env;
+
+    #[test]
+    fn test_parse_sidecar() {
+        let expected = parse_sidecar("# alice\n1 142\n\n2 -281 # checked by hand\n").unwrap();
+        assert_eq!(expected, HashMap::from([(1, String::from("142")), (2, String::from("-281"))]));
+        assert!(parse_sidecar("1 142 extra").is_err());
+        assert!(parse_sidecar("one 142").is_err());
+    }
+
+    #[test]
+    fn test_inputs() {
+        let dir = env::temp_dir().join(format!("batch-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for name in ["bob.txt", "alice.txt", "alice.answers", ".hidden"] {
+            fs::write(dir.join(name), "").unwrap();
+        }
+        assert_eq!(inputs(&dir).unwrap(), vec!(dir.join("alice.txt"), dir.join("bob.txt")));
+        assert_eq!(sidecar(&dir.join("bob.txt")), Ok(HashMap::new()));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_table() {
+        let day = days::find("dayone").unwrap();
+        let result = |answer: &str| Ok(RunResult {
+            answer: Some(String::from(answer)),
+            elapsed: Duration::from_micros(1500),
+            output: String::new(),
+            success: true,
+            timed_out: None,
+        });
+        let rows = vec!(
+            BatchRow { file: String::from("alice.txt"), part: &day.parts[0], result: result("142"), expected: Some(String::from("142")) },
+            BatchRow { file: String::from("alice.txt"), part: &day.parts[1], result: result("281"), expected: Some(String::from("280")) },
+            BatchRow { file: String::from("bob.txt"), part: &day.parts[0], result: result("77"), expected: None },
+            BatchRow { file: String::from("bob.txt"), part: &day.parts[1], result: Err(String::from("Missing input: bob.txt")), expected: None },
+        );
+        assert_eq!(rows.iter().map(BatchRow::failed).collect::<Vec<_>>(), vec!(false, true, false, true));
+        assert_eq!(table(&rows),
+          "file       part  answer     time  status\n\
+           alice.txt     1  142     1.500ms  ok\n\
+           alice.txt     2  281     1.500ms  expected 280\n\
+           bob.txt       1  77      1.500ms  unrecorded\n\
+           bob.txt       2                   Missing input: bob.txt\n");
+    }
+}
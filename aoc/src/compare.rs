@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+use crate::days::Part;
+use crate::runner::{self, TimedOut};
+
+// How one strategy did on the input.
+#[derive(Debug)]
+pub struct Outcome {
+    pub strategy: &'static str,
+    // The answer and the fastest time it was found in.
+    pub result: Result<(String, Duration), TimedOut>,
+}
+
+// Every strategy of a part, run on the same input.
+#[derive(Debug)]
+pub struct Comparison {
+    pub outcomes: Vec<Outcome>,
+}
+
+// Runs each of a part's strategies `runs` times, keeping its fastest time.
+pub fn compare(part: &'static Part, input: &str, budget: Duration, runs: usize) -> Comparison {
+    let outcomes = part.strategies.iter().map(|strategy| {
+        let mut fastest: Option<(String, Duration)> = None;
+        for _ in 0..runs.max(1) {
+            match runner::solve_within(strategy, String::from(input), budget, |_, _| ()) {
+                Ok((answer, elapsed)) => {
+                    if fastest.as_ref().is_none_or(|(_, best)| elapsed < *best) {
+                        fastest = Some((answer, elapsed));
+                    }
+                },
+                Err(timed_out) => return Outcome { strategy: strategy.name, result: Err(timed_out) },
+            }
+        }
+        Outcome { strategy: strategy.name, result: Ok(fastest.unwrap()) }
+    }).collect();
+    Comparison { outcomes }
+}
+
+impl Comparison {
+    fn answers(&self) -> impl Iterator<Item = (&str, Duration)> {
+        self.outcomes.iter().filter_map(|outcome| {
+            outcome.result.as_ref().ok().map(|(answer, elapsed)| (answer.as_str(), *elapsed))
+        })
+    }
+
+    // Whether every strategy that finished found the same answer.
+    pub fn agree(&self) -> bool {
+        let mut answers = self.answers().map(|(answer, _)| answer);
+        let first = answers.next();
+        answers.all(|answer| Some(answer) == first)
+    }
+
+    // One line per strategy, with its time relative to the fastest.
+    pub fn report(&self) -> String {
+        let fastest = self.answers().map(|(_, elapsed)| elapsed).min().unwrap_or_default();
+        let name_width = self.outcomes.iter().map(|outcome| outcome.strategy.len()).max().unwrap_or(0);
+        let answer_width = self.answers().map(|(answer, _)| answer.len()).max().unwrap_or(0);
+        self.outcomes.iter().map(|outcome| {
+            let result = match &outcome.result {
+                Ok((answer, elapsed)) if *elapsed == fastest => {
+                    format!("{answer:answer_width$}  {:>10.3}ms  fastest", elapsed.as_secs_f64() * 1000.0)
+                },
+                Ok((answer, elapsed)) => {
+                    let relative = elapsed.as_secs_f64() / fastest.as_secs_f64().max(f64::MIN_POSITIVE);
+                    format!("{answer:answer_width$}  {:>10.3}ms  {relative:.2}x", elapsed.as_secs_f64() * 1000.0)
+                },
+                Err(timed_out) => {
+                    let stage = timed_out.stage.as_deref().unwrap_or("an unknown stage");
+                    format!("timed out in {stage} after {:.3}s", timed_out.elapsed.as_secs_f64())
+                },
+            };
+            format!("  {:name_width$}  {result}\n", outcome.strategy)
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::compare::*;
+    use crate::days::{self, InputKind};
+
+    fn outcome(strategy: &'static str, answer: &str, millis: u64) -> Outcome {
+        Outcome { strategy, result: Ok((String::from(answer), Duration::from_millis(millis))) }
+    }
+
+    #[test]
+    fn test_compare() {
+        let day = days::find("day6").unwrap();
+        let input = runner::read_input(day, InputKind::Example, 1).unwrap();
+        let comparison = compare(&day.parts[0], &input, Duration::from_secs(5), 2);
+        assert_eq!(comparison.outcomes.iter().map(|o| o.strategy).collect::<Vec<_>>(), vec!("brute force", "quadratic"));
+        assert!(comparison.agree());
+    }
+
+    #[test]
+    fn test_report() {
+        let comparison = Comparison { outcomes: vec!(
+            outcome("brute force", "46", 30),
+            outcome("spans", "46", 10),
+            Outcome { strategy: "guess", result: Err(TimedOut { stage: Some(String::from("guessing")), elapsed: Duration::from_secs(2) }) },
+        )};
+        assert!(comparison.agree());
+        assert_eq!(comparison.report(),
+          "  brute force  46      30.000ms  3.00x\n\
+          \x20 spans        46      10.000ms  fastest\n\
+          \x20 guess        timed out in guessing after 2.000s\n");
+    }
+
+    #[test]
+    fn test_disagree() {
+        let comparison = Comparison { outcomes: vec!(outcome("nom tokenizer", "281", 2), outcome("scan", "280", 1)) };
+        assert!(!comparison.agree());
+    }
+}
@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+use std::io;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+
+use ratatui::DefaultTerminal;
+use ratatui::Frame;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Paragraph, Row, Table, TableState};
+
+use crate::answers::{Answers, Verdict};
+use crate::days::{Day, InputKind, Part, DAYS};
+use crate::runner::{self, RunResult, RunStatus};
+
+// Where a single (day, input) stands.
+enum Status {
+    Running,
+    Done(RunResult),
+    Failed(String),
+}
+
+// A finished run, sent back from a worker thread.
+struct Finished {
+    row: usize,
+    kind: InputKind,
+    result: Result<RunResult, String>,
+}
+
+struct App {
+    // Every part of every day, one per row.
+    rows: Vec<(&'static Day, &'static Part)>,
+    answers: Answers,
+    input: InputKind,
+    table: TableState,
+    results: HashMap<(usize, InputKind), Status>,
+    // Scroll position of the debug output, when it's open.
+    debug: Option<u16>,
+    sender: Sender<Finished>,
+    receiver: Receiver<Finished>,
+    quit: bool,
+}
+
+impl App {
+    fn new(answers: Answers) -> App {
+        let (sender, receiver) = channel();
+        App {
+            rows: DAYS.iter().flat_map(|day| day.parts.iter().map(move |part| (day, part))).collect(),
+            answers,
+            input: InputKind::Example,
+            table: TableState::default().with_selected(0),
+            results: HashMap::new(),
+            debug: None,
+            sender,
+            receiver,
+            quit: false,
+        }
+    }
+
+    fn selected(&self) -> usize {
+        self.table.selected().unwrap_or(0)
+    }
+
+    // Kicks off a run on a worker thread, so slow days don't freeze the screen.
+    fn start(&mut self, row: usize) {
+        let kind = self.input;
+        if let Some(Status::Running) = self.results.get(&(row, kind)) {
+            return;
+        }
+        self.results.insert((row, kind), Status::Running);
+        let (day, part) = self.rows[row];
+        let sender = self.sender.clone();
+        thread::spawn(move || {
+            let result = runner::run_isolated(day, part, &day.input_path(kind, part.number), runner::DEFAULT_BUDGET);
+            // The dashboard may have quit already, that's fine.
+            let _ = sender.send(Finished { row, kind, result });
+        });
+    }
+
+    fn collect_finished(&mut self) {
+        while let Ok(finished) = self.receiver.try_recv() {
+            let status = match finished.result {
+                Ok(result) => Status::Done(result),
+                Err(message) => Status::Failed(message),
+            };
+            self.results.insert((finished.row, finished.kind), status);
+        }
+    }
+
+    fn handle_key(&mut self, code: KeyCode) {
+        if let Some(scroll) = self.debug {
+            match code {
+                KeyCode::Esc | KeyCode::Char('d') | KeyCode::Char('q') => self.debug = None,
+                KeyCode::Up | KeyCode::Char('k') => self.debug = Some(scroll.saturating_sub(1)),
+                KeyCode::Down | KeyCode::Char('j') => self.debug = Some(scroll.saturating_add(1)),
+                KeyCode::PageUp => self.debug = Some(scroll.saturating_sub(20)),
+                KeyCode::PageDown => self.debug = Some(scroll.saturating_add(20)),
+                _ => (),
+            }
+            return;
+        }
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Up | KeyCode::Char('k') => self.table.select_previous(),
+            KeyCode::Down | KeyCode::Char('j') if self.selected() + 1 < self.rows.len() => self.table.select_next(),
+            KeyCode::Char('r') => self.start(self.selected()),
+            KeyCode::Char('a') => (0..self.rows.len()).for_each(|row| self.start(row)),
+            KeyCode::Char('e') => self.input = self.input.toggle(),
+            KeyCode::Char('d') | KeyCode::Enter => self.debug = Some(0),
+            _ => (),
+        }
+    }
+
+    fn row(&self, idx: usize) -> Row<'static> {
+        let (day, part) = self.rows[idx];
+        let (answer, status, time) = match self.results.get(&(idx, self.input)) {
+            None => (String::new(), Line::from("-"), String::new()),
+            Some(Status::Running) => (String::new(), Line::from("running").yellow(), String::new()),
+            Some(Status::Failed(_)) => (String::new(), Line::from("error").red(), String::new()),
+            Some(Status::Done(result)) => {
+                let time = format!("{:.1?}", result.elapsed);
+                let status = result.status(&self.answers, day, part, self.input);
+                let line = Line::from(status.to_string());
+                let line = match status {
+                    RunStatus::Answered(Verdict::Correct) => line.green(),
+                    RunStatus::Answered(Verdict::Unrecorded) => line.dark_gray(),
+                    _ => line.red(),
+                };
+                (result.answer.clone().unwrap_or_default(), line, time)
+            },
+        };
+        Row::new(vec![
+            Line::from(day.name),
+            Line::from(format!("{}", part.number)),
+            Line::from(answer),
+            status,
+            Line::from(time),
+        ])
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [header, body, footer] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ]).areas(frame.area());
+
+        frame.render_widget(
+            Line::from(format!("Advent of Code 2023 - {} input", self.input)).bold(),
+            header);
+
+        if let Some(scroll) = self.debug {
+            let idx = self.selected();
+            let text = match self.results.get(&(idx, self.input)) {
+                None => String::from("Not run yet, press 'r' to run."),
+                Some(Status::Running) => String::from("Still running..."),
+                Some(Status::Failed(message)) => message.clone(),
+                Some(Status::Done(result)) => result.output.clone(),
+            };
+            let (day, part) = self.rows[idx];
+            let title = format!(" {} part {} debug output ({} input) ", day.name, part.number, self.input);
+            frame.render_widget(
+                Paragraph::new(text).block(Block::bordered().title(title)).scroll((scroll, 0)),
+                body);
+            frame.render_widget(Line::from("up/down/pgup/pgdn scroll, esc close").dark_gray(), footer);
+            return;
+        }
+
+        let rows: Vec<Row> = (0..self.rows.len()).map(|idx| self.row(idx)).collect();
+        let table = Table::new(rows, [
+            Constraint::Length(8),
+            Constraint::Length(5),
+            Constraint::Length(20),
+            Constraint::Min(12),
+            Constraint::Length(12),
+        ])
+          .header(Row::new(vec!["Day", "Part", "Answer", "Status", "Time"]).bold())
+          .block(Block::bordered())
+          .row_highlight_style(Style::new().fg(Color::Black).bg(Color::Gray));
+        frame.render_stateful_widget(table, body, &mut self.table);
+        frame.render_widget(
+            Line::from("r run  a run all  e example/real  d debug output  q quit").dark_gray(),
+            footer);
+    }
+}
+
+fn event_loop(terminal: &mut DefaultTerminal, app: &mut App) -> io::Result<()> {
+    while !app.quit {
+        app.collect_finished();
+        terminal.draw(|frame| app.draw(frame))?;
+        if event::poll(Duration::from_millis(100))? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    app.handle_key(key.code);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// Runs the full-screen dashboard until the user quits.
+pub fn run() -> io::Result<()> {
+    let answers = Answers::load().map_err(io::Error::other)?;
+    let mut app = App::new(answers);
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, &mut app);
+    ratatui::restore();
+    result
+}
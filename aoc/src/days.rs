@@ -0,0 +1,232 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use day16::grid::Grid;
+
+use crate::generators::{self, Generator};
+use crate::minimize::Shape;
+use crate::visualize;
+
+// Which puzzle input a day is run against.
+//
+// Example inputs are the ones from the puzzle text, checked in as
+// `example.txt` next to each day, or `example<part>.txt` when a part's
+// example differs. Real inputs are everyone's own, and live wherever that
+// day's binary expects to find them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InputKind {
+    Example,
+    Real,
+}
+
+impl InputKind {
+    pub fn toggle(&self) -> InputKind {
+        match self {
+            InputKind::Example => InputKind::Real,
+            InputKind::Real => InputKind::Example,
+        }
+    }
+}
+
+impl fmt::Display for InputKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputKind::Example => write!(f, "example"),
+            InputKind::Real => write!(f, "real"),
+        }
+    }
+}
+
+// One way of solving a part, from puzzle input to answer.
+#[derive(Debug)]
+pub struct Strategy {
+    pub name: &'static str,
+    pub solve: fn(&str) -> String,
+}
+
+// A part of a day, and every strategy that solves it. The first strategy is
+// the one normally used, the rest are there to compare against.
+#[derive(Debug)]
+pub struct Part {
+    pub number: u8,
+    pub strategies: &'static [Strategy],
+}
+
+// A day's crate, and the parts it solves.
+#[derive(Debug)]
+pub struct Day {
+    // Directory of the crate, also used as the day's name.
+    pub name: &'static str,
+    pub number: u8,
+    // File the day's own binary reads as its real input.
+    pub input_file: &'static str,
+    pub parts: &'static [Part],
+    pub generator: Generator,
+    // Draws the day's input as SVG, for days with something to see.
+    pub visualization: Option<fn(&str) -> String>,
+    // How the input is laid out, for cutting it down when minimizing.
+    pub shape: Shape,
+}
+
+pub const DAYS: &[Day] = &[
+    Day { name: "dayone", number: 1, input_file: "calibration.txt", parts: &[
+        Part { number: 1, strategies: &[
+            Strategy { name: "digits", solve: |input| dayone::part_one(input).to_string() },
+            Strategy { name: "bidirectional", solve: |input| dayone::bidirectional::part_one(input).to_string() },
+        ]},
+        // Number words are used up once found. The bidirectional extractor
+        // only reads overlapping ones, so it has no part two strategy here.
+        Part { number: 2, strategies: &[
+            Strategy { name: "nom tokenizer", solve: |input| dayone::decode_msg(input).to_string() },
+            Strategy { name: "scan", solve: |input| dayone::decode_msg_scan(input).to_string() },
+            Strategy { name: "automaton", solve: |input| dayone::automaton::decode_msg_automaton(input).to_string() },
+            Strategy { name: "parallel", solve: |input| {
+                let words = dayone::extractor::Words::english(dayone::Tokenizer::Consuming);
+                dayone::stream::decode_parallel(input, &words).unwrap().to_string()
+            }},
+        ]},
+    ], generator: Generator { typical_size: 1000, generate: generators::calibration_document },
+      visualization: None, shape: Shape::Lines },
+    Day { name: "day2", number: 2, input_file: "results.txt", parts: &[
+        Part { number: 1, strategies: &[
+            Strategy { name: "nom", solve: |input| day2::possible_game_sums(input).to_string() },
+        ]},
+        Part { number: 2, strategies: &[
+            Strategy { name: "nom", solve: |input| day2::total_game_sum(input).to_string() },
+        ]},
+    ], generator: Generator { typical_size: 100, generate: generators::cube_games },
+      visualization: None, shape: Shape::Lines },
+    Day { name: "day4", number: 4, input_file: "results.txt", parts: &[
+        Part { number: 2, strategies: &[
+            Strategy { name: "copy counts", solve: |input| day4::part_two(input).to_string() },
+        ]},
+    ], generator: Generator { typical_size: 200, generate: generators::scratchcards },
+      visualization: None, shape: Shape::Lines },
+    Day { name: "day5", number: 5, input_file: "input.txt", parts: &[
+        Part { number: 1, strategies: &[
+            Strategy { name: "lookup", solve: |input| day5::part_one_answer(input).to_string() },
+        ]},
+        Part { number: 2, strategies: &[
+            Strategy { name: "brute force", solve: |input| day5::part_two_answer(input).to_string() },
+            Strategy { name: "spans", solve: |input| day5::part_two_spans_answer(input).to_string() },
+        ]},
+    ], generator: Generator { typical_size: 30, generate: generators::almanac },
+      visualization: None, shape: Shape::Sections },
+    Day { name: "day6", number: 6, input_file: "input.txt", parts: &[
+        Part { number: 1, strategies: &[
+            Strategy { name: "brute force", solve: |input| {
+                let (_, records) = day6::parse_input(input).unwrap();
+                day6::part_one(records).to_string()
+            }},
+            Strategy { name: "quadratic", solve: |input| {
+                let (_, records) = day6::parse_input(input).unwrap();
+                day6::part_one_quadratic(records).to_string()
+            }},
+        ]},
+    ], generator: Generator { typical_size: 4, generate: generators::race_sheet },
+      visualization: None, shape: Shape::Columns },
+    Day { name: "day7", number: 7, input_file: "input.txt", parts: &[
+        Part { number: 1, strategies: &[
+            Strategy { name: "sort hands", solve: |input| day7::part_one_solution(day7::parse_input(input)).to_string() },
+        ]},
+    ], generator: Generator { typical_size: 1000, generate: generators::camel_bids },
+      visualization: None, shape: Shape::Lines },
+    Day { name: "day8", number: 8, input_file: "input.txt", parts: &[
+        Part { number: 1, strategies: &[
+            Strategy { name: "walk", solve: |input| {
+                let (_, (directions, nodes)) = day8::parse_input(input).unwrap();
+                day8::solve_part_1(directions, nodes).to_string()
+            }},
+        ]},
+    ], generator: Generator { typical_size: 750, generate: generators::network },
+      visualization: None, shape: Shape::Header(2) },
+    Day { name: "day9", number: 9, input_file: "input.txt", parts: &[
+        Part { number: 1, strategies: &[
+            Strategy { name: "differences", solve: |input| day9::part1_solution(day9::parse_input(input)).to_string() },
+        ]},
+    ], generator: Generator { typical_size: 200, generate: generators::oasis_report },
+      visualization: None, shape: Shape::Lines },
+    Day { name: "day10", number: 10, input_file: "input.txt", parts: &[
+        Part { number: 1, strategies: &[
+            Strategy { name: "bfs", solve: |input| day10::solve(day10::parse_input(input)).to_string() },
+        ]},
+    ], generator: Generator { typical_size: 140, generate: generators::pipe_maze },
+      visualization: Some(visualize::pipe_loop), shape: Shape::Grid },
+    Day { name: "day11", number: 11, input_file: "input.txt", parts: &[
+        Part { number: 1, strategies: &[
+            Strategy { name: "pairs", solve: |input| day11::solve_part1(input).to_string() },
+        ]},
+        Part { number: 2, strategies: &[
+            Strategy { name: "pairs", solve: |input| day11::solve_part2(input).to_string() },
+        ]},
+    ], generator: Generator { typical_size: 140, generate: generators::galaxy_map },
+      visualization: Some(visualize::expanded_galaxies), shape: Shape::Grid },
+    Day { name: "day16", number: 16, input_file: "input.txt", parts: &[
+        Part { number: 1, strategies: &[
+            Strategy { name: "raytrace", solve: |input| {
+                let grid: Grid<char> = input.into();
+                day16::raytrace(&grid).to_string()
+            }},
+        ]},
+    ], generator: Generator { typical_size: 110, generate: generators::mirror_grid },
+      visualization: Some(visualize::energized_tiles), shape: Shape::Grid },
+];
+
+// Finds a day by its name ("day5", "dayone") or number ("5", "1").
+pub fn find(name: &str) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.name == name || day.number.to_string() == name)
+}
+
+// The root of the repository, where all the day crates live.
+pub fn repo_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+impl Part {
+    // The strategy normally used to solve the part.
+    pub fn strategy(&self) -> &'static Strategy {
+        &self.strategies[0]
+    }
+
+    pub fn find_strategy(&self, name: &str) -> Option<&'static Strategy> {
+        self.strategies.iter().find(|strategy| strategy.name == name)
+    }
+}
+
+impl Day {
+    pub fn crate_dir(&self) -> PathBuf {
+        repo_root().join(self.name)
+    }
+
+    pub fn part(&self, number: u8) -> Option<&'static Part> {
+        self.parts.iter().find(|part| part.number == number)
+    }
+
+    // Where the input of a given kind lives for a part.
+    pub fn input_path(&self, kind: InputKind, part: u8) -> PathBuf {
+        match kind {
+            InputKind::Example => {
+                let part_example = self.crate_dir().join(format!("example{part}.txt"));
+                if part_example.exists() {
+                    part_example
+                } else {
+                    self.crate_dir().join("example.txt")
+                }
+            },
+            InputKind::Real => self.crate_dir().join(self.input_file),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::*;
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find("dayone").map(|d| d.number), Some(1));
+        assert_eq!(find("1").map(|d| d.name), Some("dayone"));
+        assert_eq!(find("day16").map(|d| d.number), Some(16));
+        assert!(find("day3").is_none());
+    }
+}
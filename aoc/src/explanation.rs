@@ -0,0 +1,99 @@
+use explain::Event;
+use serde_json::{json, Map, Value};
+
+use crate::days::{Day, Part};
+use crate::runner;
+
+// How a part reached its answer, as recorded by its trace events.
+pub struct Explanation {
+    pub day: &'static str,
+    pub part: u8,
+    pub answer: String,
+    pub events: Vec<Event>,
+}
+
+// Solves a part while capturing everything it explains along the way.
+pub fn explain(day: &'static Day, part: &Part, input: &str) -> Explanation {
+    let ((answer, _), events) = explain::capture(|| runner::solve(part.strategy(), input));
+    Explanation { day: day.name, part: part.number, answer, events }
+}
+
+impl Explanation {
+    // A readable report, one numbered entry per event.
+    pub fn report(&self) -> String {
+        let mut report = format!("{} part {}: {}\n", self.day, self.part, self.answer);
+        let width = self.events.len().to_string().len();
+        for (idx, event) in self.events.iter().enumerate() {
+            report += &format!("{:>width$}. [{}] {}\n", idx + 1, event.stage, event.message);
+            for (name, value) in &event.fields {
+                report += &format!("{:width$}    {name} = {value}\n", "");
+            }
+        }
+        report
+    }
+
+    pub fn to_json(&self) -> Value {
+        let events: Vec<Value> = self.events.iter().map(|event| {
+            let fields: Map<String, Value> =
+              event.fields.iter()
+              .map(|(name, value)| (name.clone(), Value::String(value.clone())))
+              .collect();
+            json!({
+                "stage": event.stage,
+                "message": event.message,
+                "fields": fields,
+            })
+        }).collect();
+        json!({
+            "day": self.day,
+            "part": self.part,
+            "answer": self.answer,
+            "events": events,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days;
+    use crate::explanation::*;
+
+    #[test]
+    fn test_report() {
+        let day = days::find("day5").unwrap();
+        let input = runner::read_input(day, crate::days::InputKind::Example, 1).unwrap();
+        let explanation = explain(day, day.part(1).unwrap(), &input);
+        assert_eq!(explanation.report(),
+          "day5 part 1: 35\n\
+          1. [winning seed] seed 13 -> soil 13 -> fertilizer 52 -> water 41 -> light 34 -> temperature 34 -> humidity 35 -> location 35\n     \
+              seed = 13\n     \
+              chain = [13, 13, 52, 41, 34, 34, 35, 35]\n");
+    }
+
+    #[test]
+    fn test_example_reports() {
+        for day in days::DAYS {
+            for part in day.parts {
+                let input = runner::read_input(day, days::InputKind::Example, part.number).unwrap();
+                let report = explain(day, part, &input).report();
+                snapshot::assert_snapshot!(&format!("explain_{}_part{}", day.name, part.number), report);
+            }
+        }
+    }
+
+    #[test]
+    fn test_json() {
+        let explanation = Explanation {
+            day: "day9",
+            part: 1,
+            answer: String::from("18"),
+            events: vec!(Event {
+                stage: String::from("extrapolate"),
+                message: String::from("continues with 18"),
+                fields: vec!((String::from("next"), String::from("18")), (String::from("depth"), String::from("2"))),
+            }),
+        };
+        assert_eq!(explanation.to_json().to_string(),
+          r#"{"day":"day9","part":1,"answer":"18","events":[{"stage":"extrapolate","message":"continues with 18","fields":{"next":"18","depth":"2"}}]}"#);
+    }
+}
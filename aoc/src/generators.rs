@@ -0,0 +1,344 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+use rand::seq::index::sample;
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashSet;
+
+// Writes random, valid puzzle inputs for a day.
+//
+// `size` is how big to make it: lines for line based inputs, records for
+// race sheets, ranges per map for almanacs, nodes for networks, and the side
+// of the grid for grid inputs.
+#[derive(Debug)]
+pub struct Generator {
+    // Roughly the size of a real input.
+    pub typical_size: usize,
+    pub generate: fn(&mut ChaCha8Rng, usize) -> String,
+}
+
+const DIGIT_WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+fn lowercase(rng: &mut ChaCha8Rng) -> String {
+    (0..rng.gen_range(1..=5)).map(|_| rng.gen_range('a'..='z')).collect()
+}
+
+// two1nine, with at least one real digit so part one can decode it.
+pub fn calibration_document(rng: &mut ChaCha8Rng, size: usize) -> String {
+    (0..size).map(|_| {
+        let mut pieces: Vec<String> = (0..rng.gen_range(1..=6)).map(|_| match rng.gen_range(0..3) {
+            0 => lowercase(rng),
+            1 => rng.gen_range(1..=9).to_string(),
+            _ => String::from(*DIGIT_WORDS.choose(rng).unwrap()),
+        }).collect();
+        let at = rng.gen_range(0..=pieces.len());
+        pieces.insert(at, rng.gen_range(1..=9).to_string());
+        pieces.concat() + "\n"
+    }).collect()
+}
+
+// Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+pub fn cube_games(rng: &mut ChaCha8Rng, size: usize) -> String {
+    (1..=size).map(|game| {
+        let draws: Vec<String> = (0..rng.gen_range(1..=6)).map(|_| {
+            let mut colors = ["red", "green", "blue"];
+            colors.shuffle(rng);
+            let shown = rng.gen_range(1..=colors.len());
+            colors[..shown].iter()
+              .map(|color| format!("{} {color}", rng.gen_range(1..=20)))
+              .collect::<Vec<_>>()
+              .join(", ")
+        }).collect();
+        format!("Game {game}: {}\n", draws.join("; "))
+    }).collect()
+}
+
+fn padded_numbers(numbers: &[u32]) -> String {
+    numbers.iter().map(|n| format!("{n:>2}")).collect::<Vec<_>>().join(" ")
+}
+
+// Card   1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+//
+// No card wins copies of cards past the end of the table, and wins are
+// held back once copies run into the millions, so part two's total still
+// fits in a u32.
+pub fn scratchcards(rng: &mut ChaCha8Rng, size: usize) -> String {
+    const WINNERS: usize = 10;
+    const HAND: usize = 25;
+    const MAX_COPIES: u64 = 1_000_000;
+    let mut copies = vec![1u64; size];
+    (0..size).map(|idx| {
+        let numbers: Vec<u32> = sample(rng, 99, WINNERS + HAND).iter().map(|n| n as u32 + 1).collect();
+        let (winners, others) = numbers.split_at(WINNERS);
+        let mut matches = rng.gen_range(0..=WINNERS.min(size - 1 - idx));
+        while copies[idx + 1..=idx + matches].iter().any(|n| n + copies[idx] > MAX_COPIES) {
+            matches -= 1;
+        }
+        for j in idx + 1..=idx + matches {
+            copies[j] += copies[idx];
+        }
+        let mut hand: Vec<u32> = winners[..matches].iter().chain(&others[..HAND - matches]).copied().collect();
+        hand.shuffle(rng);
+        format!("Card {:>3}: {} | {}\n", idx + 1, padded_numbers(winners), padded_numbers(&hand))
+    }).collect()
+}
+
+const CATEGORIES: [&str; 8] = ["seed", "soil", "fertilizer", "water", "light", "temperature", "humidity", "location"];
+
+// seeds: 79 14 55 13, then one map of `size` ranges per category.
+//
+// Seed ranges stay short, so part two can still brute force them.
+pub fn almanac(rng: &mut ChaCha8Rng, size: usize) -> String {
+    const SPAN: i64 = 1_000_000;
+    let seeds: Vec<String> = (0..10).flat_map(|_| {
+        [rng.gen_range(0..SPAN), rng.gen_range(1..=1000)]
+    }).map(|n| n.to_string()).collect();
+    let mut almanac = format!("seeds: {}\n", seeds.join(" "));
+    for pair in CATEGORIES.windows(2) {
+        almanac += &format!("\n{}-to-{} map:\n", pair[0], pair[1]);
+        // Split the sources into disjoint ranges, then map them anywhere.
+        let mut starts: Vec<i64> = sample(rng, SPAN as usize, size.max(1) + 1).iter().map(|n| n as i64).collect();
+        starts.sort();
+        let mut ranges: Vec<String> = starts.windows(2).map(|range| {
+            let length = range[1] - range[0];
+            format!("{} {} {length}", rng.gen_range(0..SPAN), range[0])
+        }).collect();
+        ranges.shuffle(rng);
+        almanac += &(ranges.join("\n") + "\n");
+    }
+    almanac
+}
+
+// Time:      7  15   30
+// Distance:  9  40  200
+//
+// Every record can be beaten.
+pub fn race_sheet(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let races: Vec<(i64, i64)> = (0..size.max(1)).map(|_| {
+        let time: i64 = rng.gen_range(7..=90);
+        let best = (time / 2) * (time - time / 2);
+        (time, rng.gen_range(0..best))
+    }).collect();
+    let times: String = races.iter().map(|(time, _)| format!(" {time:>4}")).collect();
+    let distances: String = races.iter().map(|(_, distance)| format!(" {distance:>4}")).collect();
+    format!("Time:    {times}\nDistance:{distances}\n")
+}
+
+// 32T3K 765
+pub fn camel_bids(rng: &mut ChaCha8Rng, size: usize) -> String {
+    const CARDS: &[u8] = b"23456789TJQKA";
+    (0..size).map(|_| {
+        let hand: String = (0..5).map(|_| *CARDS.choose(rng).unwrap() as char).collect();
+        format!("{hand} {}\n", rng.gen_range(1..=1000))
+    }).collect()
+}
+
+fn node_name(idx: usize) -> String {
+    let letter = |n: usize| (b'A' + (n % 26) as u8) as char;
+    [letter(idx / 676), letter(idx / 26), letter(idx)].iter().collect()
+}
+
+// RL, a blank line, then `AAA = (BBB, CCC)` for `size` nodes.
+//
+// The instructions always lead from AAA to ZZZ, along a path through some
+// of the nodes, so part one finishes.
+pub fn network(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let (first, last) = (0, 26 * 26 * 26 - 1);
+    let mut names: Vec<String> =
+      sample(rng, last - 1, size.max(2) - 2).iter()
+      .map(|n| node_name(n + 1))
+      .collect();
+    let directions: Vec<char> = (0..rng.gen_range(1..=size.max(2))).map(|_| *['L', 'R'].choose(rng).unwrap()).collect();
+    let path_len = rng.gen_range(0..=names.len());
+    let mut path = vec!(node_name(first));
+    path.extend(names[..path_len].iter().cloned());
+    path.push(node_name(last));
+    names.extend([node_name(first), node_name(last)]);
+    let mut nodes: Vec<String> = names.iter().map(|name| {
+        let mut sides = [names.choose(rng).unwrap(), names.choose(rng).unwrap()];
+        if let Some(step) = path.iter().position(|node| node == name).filter(|step| *step + 1 < path.len()) {
+            let side = if directions[step % directions.len()] == 'L' { 0 } else { 1 };
+            sides[side] = &path[step + 1];
+        }
+        format!("{name} = ({}, {})", sides[0], sides[1])
+    }).collect();
+    nodes.shuffle(rng);
+    format!("{}\n\n{}\n", String::from_iter(directions), nodes.join("\n"))
+}
+
+// 0 3 6 9 12 15, sampled from random polynomials so differences reach zero.
+pub fn oasis_report(rng: &mut ChaCha8Rng, size: usize) -> String {
+    (0..size).map(|_| {
+        let coefficients: Vec<i64> = (0..=rng.gen_range(0..=5)).map(|_| rng.gen_range(-5..=5)).collect();
+        let values: Vec<String> = (0..21i64).map(|x| {
+            coefficients.iter().rev().fold(0, |acc, c| acc * x + c).to_string()
+        }).collect();
+        values.join(" ") + "\n"
+    }).collect()
+}
+
+fn grid_text(cells: &[Vec<char>]) -> String {
+    cells.iter().map(|row| String::from_iter(row) + "\n").collect()
+}
+
+// The pipe joining two neighbours of a tile, given as (dx, dy) offsets.
+fn pipe(a: (i64, i64), b: (i64, i64)) -> char {
+    let (north, south, east, west) = ((0, -1), (0, 1), (1, 0), (-1, 0));
+    let joins = |x, y| (a == x && b == y) || (a == y && b == x);
+    if joins(north, south) { '|' }
+    else if joins(east, west) { '-' }
+    else if joins(north, east) { 'L' }
+    else if joins(north, west) { 'J' }
+    else if joins(south, west) { '7' }
+    else { 'F' }
+}
+
+// ..F7.
+// .FJ|.
+// SJ.L7
+//
+// A single loop through S, with a skyline for a top edge and a flat bottom,
+// in a field of stray pipes that never connect to it.
+pub fn pipe_maze(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let side = size.max(5);
+    let mut cells: Vec<Vec<char>> = (0..side).map(|_| {
+        (0..side).map(|_| if rng.gen_bool(0.5) { '.' } else { *['|', '-', 'L', 'J', '7', 'F'].choose(rng).unwrap() }).collect()
+    }).collect();
+    let (width, height) = (rng.gen_range(2..=side), rng.gen_range(3..=side));
+    let (left, top) = (rng.gen_range(0..=side - width) as i64, rng.gen_range(0..=side - height) as i64);
+    let (width, height) = (width as i64, height as i64);
+    let skyline: Vec<i64> = (0..width).map(|_| rng.gen_range(0..=height - 3)).collect();
+
+    // Up the left edge, along the skyline, down the right edge, then back.
+    let mut path: Vec<(i64, i64)> = (skyline[0]..height).rev().map(|y| (0, y)).collect();
+    for x in 1..width {
+        let (from, to) = (skyline[x as usize - 1], skyline[x as usize]);
+        path.push((x, from));
+        if to > from {
+            path.extend((from + 1..=to).map(|y| (x, y)));
+        } else {
+            path.extend((to..from).rev().map(|y| (x, y)));
+        }
+    }
+    path.extend((skyline[width as usize - 1] + 1..height).map(|y| (width - 1, y)));
+    path.extend((1..width - 1).rev().map(|x| (x, height - 1)));
+
+    let offset = |(x, y): (i64, i64), (nx, ny): (i64, i64)| (nx - x, ny - y);
+    for (idx, tile) in path.iter().enumerate() {
+        let prev = path[(idx + path.len() - 1) % path.len()];
+        let next = path[(idx + 1) % path.len()];
+        cells[(top + tile.1) as usize][(left + tile.0) as usize] = pipe(offset(*tile, prev), offset(*tile, next));
+    }
+    let (sx, sy) = *path.choose(rng).unwrap();
+    let (sx, sy) = (left + sx, top + sy);
+    cells[sy as usize][sx as usize] = 'S';
+    // Stray pipes could connect to S from any side.
+    for (x, y) in [(sx, sy - 1), (sx, sy + 1), (sx - 1, sy), (sx + 1, sy)] {
+        if (0..side as i64).contains(&x) && (0..side as i64).contains(&y) && !path.contains(&(x - left, y - top)) {
+            cells[y as usize][x as usize] = '.';
+        }
+    }
+    grid_text(&cells)
+}
+
+// ...#......
+// .......#..
+//
+// A square of space where a tenth of the rows and columns are left empty.
+pub fn galaxy_map(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let side = size.max(1);
+    let empty_rows: HashSet<usize> = (0..side).filter(|_| rng.gen_bool(0.1)).collect();
+    let empty_cols: HashSet<usize> = (0..side).filter(|_| rng.gen_bool(0.1)).collect();
+    let cells: Vec<Vec<char>> = (0..side).map(|row| {
+        (0..side).map(|col| {
+            let empty = empty_rows.contains(&row) || empty_cols.contains(&col);
+            if !empty && rng.gen_bool(0.05) { '#' } else { '.' }
+        }).collect()
+    }).collect();
+    grid_text(&cells)
+}
+
+// .|...\....
+// |.-.\.....
+//
+// A square contraption, mostly empty space.
+pub fn mirror_grid(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let side = size.max(1);
+    let cells: Vec<Vec<char>> = (0..side).map(|_| {
+        (0..side).map(|_| if rng.gen_bool(0.85) { '.' } else { *['|', '-', '/', '\\'].choose(rng).unwrap() }).collect()
+    }).collect();
+    grid_text(&cells)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::combinator::all_consuming;
+    use rand::SeedableRng;
+
+    use crate::days::{self, Day, DAYS};
+    use crate::generators::*;
+
+    fn generate_for(day: &Day, seed: u64, size: usize) -> String {
+        (day.generator.generate)(&mut ChaCha8Rng::seed_from_u64(seed), size)
+    }
+
+    fn generate(name: &str, seed: u64, size: usize) -> String {
+        generate_for(days::find(name).unwrap(), seed, size)
+    }
+
+    #[test]
+    fn test_same_seed_same_input() {
+        for day in DAYS {
+            assert_eq!(generate_for(day, 7, 20), generate_for(day, 7, 20), "{}", day.name);
+            assert_ne!(generate_for(day, 7, 20), generate_for(day, 8, 20), "{}", day.name);
+        }
+    }
+
+    // Every input parses with its day's own parser.
+    #[test]
+    fn test_inputs_parse() {
+        for seed in 0..20 {
+            let input = generate("dayone", seed, 50);
+            assert!(input.lines().all(|line| dayone::decode_line(line) > 0));
+            assert!(generate("day2", seed, 50).lines().all(|line| all_consuming(day2::game_results)(line).is_ok()));
+            assert!(generate("day4", seed, 50).lines().all(|line| all_consuming(day4::line_parser)(line).is_ok()));
+            let almanac_input = generate("day5", seed, 50);
+            let (rest, almanac) = day5::parse_input(&almanac_input).unwrap();
+            assert_eq!((rest, almanac.seeds.len(), almanac.mappings.len()), ("\n", 20, 7));
+            let race_input = generate("day6", seed, 4);
+            let (rest, records) = day6::parse_input(&race_input).unwrap();
+            assert_eq!((rest, records.len()), ("\n", 4));
+            assert_eq!(day7::parse_input(&generate("day7", seed, 50)).len(), 50);
+            let network_input = generate("day8", seed, 50);
+            let (rest, (_, nodes)) = day8::parse_input(&network_input).unwrap();
+            assert_eq!((rest, nodes.len()), ("\n", 50));
+            assert!(day9::parse_input(&generate("day9", seed, 50)).iter().all(|seq| seq.len() == 21));
+            assert_eq!(day10::parse_input(&generate("day10", seed, 50)).len(), 50 * 50);
+            let grid: day11::grid::Grid = generate("day11", seed, 50).as_str().into();
+            assert_eq!(grid.rows(), 50);
+            let grid: day16::grid::Grid<char> = generate("day16", seed, 50).as_str().into();
+            assert_eq!(grid.rows(), 50);
+        }
+    }
+
+    // Small inputs are quick to solve, and every strategy should manage. Race
+    // sheets stay at their real size, or the product of ways overflows.
+    #[test]
+    fn test_inputs_solve() {
+        for day in DAYS {
+            for seed in 0..5 {
+                let input = generate_for(day, seed, day.generator.typical_size.min(8));
+                for strategy in day.parts.iter().flat_map(|part| part.strategies) {
+                    (strategy.solve)(&input);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_pipe_maze_loop() {
+        let maze = generate("day10", 3, 12);
+        let tiles = maze.chars().filter(|c| !".\n".contains(*c)).count();
+        let answer = day10::solve(day10::parse_input(&maze));
+        assert!(answer > 0 && answer as usize <= tiles / 2);
+    }
+}
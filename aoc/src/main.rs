@@ -0,0 +1,280 @@
+mod answers;
+mod args;
+mod batch;
+mod compare;
+mod dashboard;
+mod days;
+mod explanation;
+mod generators;
+#[cfg(test)]
+mod metamorphic;
+mod minimize;
+mod report;
+mod runner;
+mod serve;
+mod visualize;
+
+use args::Args;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use days::{Day, InputKind, Part};
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Duration;
+use watchdog::Progress;
+
+const USAGE: &str = "Usage:
+  aoc [dashboard]
+  aoc run <day> [<part>] [--example | --input <file>] [--timeout <seconds>] [--strategy <name>]
+  aoc batch <day> <directory> [--timeout <seconds>]
+  aoc compare <day> [<part>] [--example | --input <file>] [--timeout <seconds>] [--runs <n>]
+  aoc explain <day> [<part>] [--example | --input <file>] [--json <file>]
+  aoc report [--example] [--output <file>] [--timeout <seconds>]
+  aoc minimize <day> [<part>] [--example | --input <file>] (--panics [--strategy <name>] | --disagree)
+              [--output <file>] [--timeout <seconds>]
+  aoc serve [--port <n>] [--timeout <seconds>]
+  aoc gen <day> [--seed <n>] [--size <n>] [--output <file>]";
+
+// Picks which parts of a day to run, all of them by default.
+fn select_parts(day: &'static Day, part: Option<&String>) -> Result<Vec<&'static Part>, String> {
+    match part {
+        None => Ok(day.parts.iter().collect()),
+        Some(number) => {
+            let number: u8 = number.parse().map_err(|_| format!("Bad part number: {number}"))?;
+            let part = day.part(number).ok_or(format!("{} has no part {number}", day.name))?;
+            Ok(vec!(part))
+        },
+    }
+}
+
+// The day and parts named by `<command> <day> [<part>]`.
+fn day_and_parts(args: &Args) -> Result<(&'static Day, Vec<&'static Part>), String> {
+    let [_, name, rest @ ..] = args.positional.as_slice() else {
+        return Err(String::from(USAGE));
+    };
+    let day = days::find(name).ok_or(format!("Unknown day: {name}"))?;
+    Ok((day, select_parts(day, rest.first())?))
+}
+
+// Reads the input chosen by `--example` or `--input`, the real input by default.
+fn load_input(args: &Args, day: &Day, part: &Part) -> Result<String, String> {
+    match args.value("input") {
+        Some(path) => fs::read_to_string(path).map_err(|e| format!("Unable to read {path}: {e}")),
+        None => {
+            let kind = if args.flag("example") { InputKind::Example } else { InputKind::Real };
+            runner::read_input(day, kind, part.number)
+        },
+    }
+}
+
+// How long each solver may run, forever unless `--timeout` says otherwise.
+fn budget(args: &Args) -> Result<Duration, String> {
+    match number::<f64>(args, "timeout")? {
+        Some(seconds) => Duration::try_from_secs_f64(seconds).map_err(|_| format!("Bad --timeout: {seconds}")),
+        None => Ok(Duration::MAX),
+    }
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    args.expect_only(&["example", "input", "timeout", "strategy"])?;
+    let (day, parts) = day_and_parts(args)?;
+    let budget = budget(args)?;
+    for part in parts {
+        let strategy = match args.value("strategy") {
+            Some(name) => part.find_strategy(name).ok_or(format!("{} part {} has no strategy {name}", day.name, part.number))?,
+            None => part.strategy(),
+        };
+        let input = load_input(args, day, part)?;
+        let report = |progress: &Progress, elapsed: Duration| {
+            let stage = progress.stage.as_deref().unwrap_or("solving");
+            let total = progress.total.map(|total| format!("/{total}")).unwrap_or_default();
+            eprintln!("{} part {}: {stage} {}{total} ({elapsed:.1?})", day.name, part.number, progress.done);
+        };
+        match runner::solve_within(strategy, input, budget, report) {
+            Ok((answer, elapsed)) => println!("{}", runner::summary(day, part, &answer, elapsed)),
+            Err(timed_out) => {
+                println!("{}", runner::timeout_summary(day, part, &timed_out));
+                return Err(String::from("Out of time"));
+            },
+        }
+    }
+    Ok(())
+}
+
+fn batch(args: &Args) -> Result<(), String> {
+    args.expect_only(&["timeout"])?;
+    let [_, name, dir] = args.positional.as_slice() else {
+        return Err(String::from(USAGE));
+    };
+    let day = days::find(name).ok_or(format!("Unknown day: {name}"))?;
+    let budget = match args.value("timeout") {
+        Some(_) => budget(args)?,
+        None => runner::DEFAULT_BUDGET,
+    };
+    let rows = batch::run(day, Path::new(dir), budget)?;
+    if rows.is_empty() {
+        return Err(format!("No inputs in {dir}"));
+    }
+    print!("{}", batch::table(&rows));
+    match rows.iter().filter(|row| row.failed()).count() {
+        0 => Ok(()),
+        failed => Err(format!("{failed} of {} runs failed or disagreed", rows.len())),
+    }
+}
+
+fn compare(args: &Args) -> Result<(), String> {
+    args.expect_only(&["example", "input", "timeout", "runs"])?;
+    let (day, parts) = day_and_parts(args)?;
+    let budget = budget(args)?;
+    let runs = number(args, "runs")?.unwrap_or(1);
+    let mut all_agree = true;
+    for part in parts {
+        let input = load_input(args, day, part)?;
+        let comparison = compare::compare(part, &input, budget, runs);
+        println!("{} part {}", day.name, part.number);
+        print!("{}", comparison.report());
+        if part.strategies.len() == 1 {
+            println!("only one strategy, nothing to compare");
+        } else if comparison.agree() {
+            println!("all {} strategies agree", part.strategies.len());
+        } else {
+            println!("strategies disagree");
+            all_agree = false;
+        }
+    }
+    if all_agree { Ok(()) } else { Err(String::from("Strategies disagree")) }
+}
+
+fn explain(args: &Args) -> Result<(), String> {
+    args.expect_only(&["example", "input", "json"])?;
+    let (day, parts) = day_and_parts(args)?;
+    let mut explanations = Vec::new();
+    for part in parts {
+        let input = load_input(args, day, part)?;
+        explanations.push(explanation::explain(day, part, &input));
+    }
+    match args.value("json") {
+        Some(path) => {
+            let json: Vec<serde_json::Value> = explanations.iter().map(|e| e.to_json()).collect();
+            let contents = serde_json::to_string_pretty(&json).map_err(|e| e.to_string())?;
+            fs::write(path, contents).map_err(|e| format!("Unable to write {path}: {e}"))?;
+            println!("Wrote {} explanations to {path}", explanations.len());
+        },
+        None => explanations.iter().for_each(|e| println!("{}", e.report())),
+    }
+    Ok(())
+}
+
+// Parses a numeric option, if it was given.
+fn number<T: std::str::FromStr>(args: &Args, name: &str) -> Result<Option<T>, String> {
+    args.value(name)
+      .map(|value| value.parse().map_err(|_| format!("Bad --{name}: {value}")))
+      .transpose()
+}
+
+fn generate(args: &Args) -> Result<(), String> {
+    args.expect_only(&["seed", "size", "output"])?;
+    let [_, name] = args.positional.as_slice() else {
+        return Err(String::from(USAGE));
+    };
+    let day = days::find(name).ok_or(format!("Unknown day: {name}"))?;
+    let seed = number(args, "seed")?.unwrap_or(0);
+    let size = number(args, "size")?.unwrap_or(day.generator.typical_size);
+    let input = (day.generator.generate)(&mut ChaCha8Rng::seed_from_u64(seed), size);
+    match args.value("output") {
+        Some(path) => fs::write(path, input).map_err(|e| format!("Unable to write {path}: {e}")),
+        None => {
+            print!("{input}");
+            Ok(())
+        },
+    }
+}
+
+fn minimize(args: &Args) -> Result<(), String> {
+    args.expect_only(&["example", "input", "panics", "strategy", "disagree", "output", "timeout"])?;
+    let (day, parts) = day_and_parts(args)?;
+    let [part] = parts.as_slice() else {
+        return Err(format!("Pick which part of {} to minimize", day.name));
+    };
+    let failure = match (args.flag("panics"), args.flag("disagree")) {
+        (true, false) => match args.value("strategy") {
+            Some(name) => minimize::Failure::Panic(
+              part.find_strategy(name).ok_or(format!("{} part {} has no strategy {name}", day.name, part.number))?),
+            None => minimize::Failure::Panic(part.strategy()),
+        },
+        (false, true) if part.strategies.len() > 1 => minimize::Failure::Disagree,
+        (false, true) => return Err(format!("{} part {} has only one strategy", day.name, part.number)),
+        _ => return Err(String::from("Say what failing means with either --panics or --disagree")),
+    };
+    let budget = match args.value("timeout") {
+        Some(_) => budget(args)?,
+        None => minimize::ATTEMPT_BUDGET,
+    };
+    let input = load_input(args, day, part)?;
+    let minimized = minimize::minimize(day, part, failure, &input, budget)?;
+    eprintln!("Cut {} lines down to {}", input.lines().count(), minimized.lines().count());
+    match args.value("output") {
+        Some(path) => fs::write(path, minimized).map_err(|e| format!("Unable to write {path}: {e}")),
+        None => {
+            print!("{minimized}");
+            Ok(())
+        },
+    }
+}
+
+fn report(args: &Args) -> Result<(), String> {
+    args.expect_only(&["example", "output", "timeout"])?;
+    let [_] = args.positional.as_slice() else {
+        return Err(String::from(USAGE));
+    };
+    let kind = if args.flag("example") { InputKind::Example } else { InputKind::Real };
+    let budget = match args.value("timeout") {
+        Some(_) => budget(args)?,
+        None => runner::DEFAULT_BUDGET,
+    };
+    let answers = answers::Answers::load()?;
+    let rows = report::run_all(kind, budget);
+    let pictures = report::draw_all(kind, budget);
+    let path = args.value("output").unwrap_or("report.html");
+    fs::write(path, report::html(kind, &rows, &pictures, &answers)).map_err(|e| format!("Unable to write {path}: {e}"))?;
+    println!("Wrote {path}");
+    Ok(())
+}
+
+fn serve(args: &Args) -> Result<(), String> {
+    args.expect_only(&["port", "timeout"])?;
+    let [_] = args.positional.as_slice() else {
+        return Err(String::from(USAGE));
+    };
+    let budget = match args.value("timeout") {
+        Some(_) => budget(args)?,
+        None => runner::DEFAULT_BUDGET,
+    };
+    serve::run(number(args, "port")?.unwrap_or(serve::DEFAULT_PORT), budget)
+}
+
+fn main() -> ExitCode {
+    let argv: Vec<String> = std::env::args().skip(1).collect();
+    let result = Args::parse(&argv, &["input", "json", "seed", "size", "output", "timeout", "runs", "strategy", "port"]).and_then(|args| {
+        match args.positional.first().map(String::as_str) {
+            None | Some("dashboard") => dashboard::run().map_err(|e| format!("Dashboard failed: {e}")),
+            Some("run") => run(&args),
+            Some("batch") => batch(&args),
+            Some("compare") => compare(&args),
+            Some("explain") => explain(&args),
+            Some("minimize") => minimize(&args),
+            Some("serve") => serve(&args),
+            Some("gen") => generate(&args),
+            Some("report") => report(&args),
+            Some(_) => Err(String::from(USAGE)),
+        }
+    });
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        },
+    }
+}
@@ -0,0 +1,205 @@
+use rand::SeedableRng;
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashMap;
+
+use day16::grid::Grid;
+use day16::location::Location;
+use day16::{Beam, Direction};
+
+use crate::days;
+
+// Changes to a day's input that must not change its answers, checked on the
+// examples and on generated inputs. A solver that only works by accident,
+// like one that mixes up rows and columns on square grids, gives itself away
+// when the same puzzle is asked another way.
+
+// A change to a day's input that leaves its answers alone.
+pub struct Relation {
+    pub day: &'static str,
+    pub name: &'static str,
+    pub transform: fn(&mut ChaCha8Rng, &str) -> String,
+    // Solves part one of the changed input, when the day's own strategies
+    // would answer a different question of it, like a mirrored day16 grid
+    // whose beam now starts somewhere else.
+    pub follow_up: Option<fn(&str) -> String>,
+}
+
+pub const RELATIONS: &[Relation] = &[
+    Relation { day: "day2", name: "shuffle games", transform: shuffle_lines, follow_up: None },
+    Relation { day: "day7", name: "shuffle bids", transform: shuffle_lines, follow_up: None },
+    Relation { day: "day8", name: "rename nodes", transform: rename_nodes, follow_up: None },
+    Relation { day: "day9", name: "shuffle histories", transform: shuffle_lines, follow_up: None },
+    Relation { day: "day11", name: "transpose", transform: transpose, follow_up: None },
+    Relation { day: "day16", name: "mirror top to bottom", transform: mirror_rows, follow_up: Some(|input| {
+        // The beam comes in along the bottom row instead.
+        let grid: Grid<char> = input.into();
+        let start = Beam { location: Location::new(0, grid.rows() as i64 - 1), direction: Direction::East };
+        day16::raytrace_from(&grid, start).to_string()
+    })},
+    Relation { day: "day16", name: "mirror along the diagonal", transform: mirror_diagonal, follow_up: Some(|input| {
+        // The beam comes in heading south instead.
+        let grid: Grid<char> = input.into();
+        let start = Beam { location: Location::new(0, 0), direction: Direction::South };
+        day16::raytrace_from(&grid, start).to_string()
+    })},
+];
+
+pub fn shuffle_lines(rng: &mut ChaCha8Rng, input: &str) -> String {
+    let mut lines: Vec<&str> = input.lines().collect();
+    lines.shuffle(rng);
+    lines.iter().map(|line| format!("{line}\n")).collect()
+}
+
+fn cells(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+pub fn transpose(_: &mut ChaCha8Rng, input: &str) -> String {
+    let cells = cells(input);
+    let cols = cells.first().map_or(0, Vec::len);
+    (0..cols).map(|col| cells.iter().map(|row| row[col]).collect::<String>() + "\n").collect()
+}
+
+// Turns the grid upside down, which turns each mirror the other way.
+pub fn mirror_rows(_: &mut ChaCha8Rng, input: &str) -> String {
+    input.lines().rev().map(|line| {
+        line.chars().map(|c| match c {
+            '/' => '\\',
+            '\\' => '/',
+            c => c,
+        }).collect::<String>() + "\n"
+    }).collect()
+}
+
+// Swaps rows for columns, which turns each splitter the other way but
+// leaves mirrors as they were.
+pub fn mirror_diagonal(rng: &mut ChaCha8Rng, input: &str) -> String {
+    transpose(rng, input).chars().map(|c| match c {
+        '|' => '-',
+        '-' => '|',
+        c => c,
+    }).collect()
+}
+
+// Gives every node but AAA and ZZZ another node's name.
+pub fn rename_nodes(rng: &mut ChaCha8Rng, input: &str) -> String {
+    let (directions, network) = input.split_once("\n\n").unwrap_or((input, ""));
+    let mut names: Vec<&str> =
+      network.lines()
+      .filter_map(|line| line.split_once(" = "))
+      .map(|(name, _)| name)
+      .filter(|name| !["AAA", "ZZZ"].contains(name))
+      .collect();
+    names.sort();
+    let mut renamed = names.clone();
+    renamed.shuffle(rng);
+    let renames: HashMap<&str, &str> = names.into_iter().zip(renamed).collect();
+    let network: String = network.lines().map(|line| {
+        let mut renamed = String::new();
+        let mut rest = line;
+        while let Some(start) = rest.find(|c: char| c.is_ascii_alphanumeric()) {
+            let end = rest[start..].find(|c: char| !c.is_ascii_alphanumeric()).map_or(rest.len(), |end| start + end);
+            let name = &rest[start..end];
+            renamed += &rest[..start];
+            renamed += renames.get(name).unwrap_or(&name);
+            rest = &rest[end..];
+        }
+        renamed + rest + "\n"
+    }).collect();
+    format!("{directions}\n\n{network}")
+}
+
+// Checks a relation on an input, for every strategy of every part.
+pub fn check(relation: &Relation, input: &str, seed: u64) -> Result<(), String> {
+    let day = days::find(relation.day).ok_or(format!("Unknown day: {}", relation.day))?;
+    let changed = (relation.transform)(&mut ChaCha8Rng::seed_from_u64(seed), input);
+    for part in day.parts {
+        for strategy in part.strategies {
+            let expected = (strategy.solve)(input);
+            let actual = match relation.follow_up {
+                Some(solve) if part.number == 1 => solve(&changed),
+                Some(_) => continue,
+                None => (strategy.solve)(&changed),
+            };
+            if actual != expected {
+                return Err(format!("{} part {} ({}) answered {actual} instead of {expected} after {}, on\n{input}",
+                  day.name, part.number, strategy.name, relation.name));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::InputKind;
+    use crate::minimize::Shape;
+    use crate::metamorphic::*;
+    use crate::runner;
+
+    fn rng() -> ChaCha8Rng {
+        ChaCha8Rng::seed_from_u64(0)
+    }
+
+    #[test]
+    fn test_transforms() {
+        assert_eq!(transpose(&mut rng(), "ab.\n..#\n"), "a.\nb.\n.#\n");
+        assert_eq!(mirror_rows(&mut rng(), "./\n|\\\n"), "|/\n.\\\n");
+        assert_eq!(mirror_diagonal(&mut rng(), "|/\n-.\n"), "-|\n/.\n");
+        let mut shuffled: Vec<String> = shuffle_lines(&mut rng(), "a\nb\nc\n").lines().map(String::from).collect();
+        shuffled.sort();
+        assert_eq!(shuffled, vec!("a", "b", "c"));
+    }
+
+    #[test]
+    fn test_rename_nodes() {
+        let input = "LR\n\nAAA = (BBB, CCC)\nBBB = (DDD, EEE)\nCCC = (ZZZ, GGG)\nDDD = (DDD, DDD)\nEEE = (EEE, EEE)\nGGG = (GGG, GGG)\nZZZ = (ZZZ, ZZZ)\n";
+        let renamed = rename_nodes(&mut rng(), input);
+        assert_ne!(renamed, input);
+        // The same names in the same places, each one renamed the same way
+        // wherever it is, and no two to the same name.
+        let names = |text: &str| text.split(|c: char| !c.is_ascii_alphanumeric()).filter(|name| !name.is_empty()).map(String::from).collect::<Vec<_>>();
+        let (before, after) = (names(input), names(&renamed));
+        assert_eq!(before.len(), after.len());
+        let mut renames: HashMap<String, String> = HashMap::new();
+        for (from, to) in before.into_iter().zip(after) {
+            assert_eq!(renames.entry(from.clone()).or_insert(to.clone()), &to, "{from} renamed two ways in\n{renamed}");
+        }
+        let mut targets: Vec<&String> = renames.values().collect();
+        targets.sort();
+        targets.dedup();
+        assert_eq!(targets.len(), renames.len());
+        for kept in ["LR", "AAA", "ZZZ"] {
+            assert_eq!(renames[kept], kept);
+        }
+        assert!(renames.iter().any(|(from, to)| from != to));
+    }
+
+    #[test]
+    fn test_relations_on_examples() {
+        for relation in RELATIONS {
+            let day = days::find(relation.day).unwrap();
+            let input = runner::read_input(day, InputKind::Example, 1).unwrap();
+            for seed in 0..3 {
+                check(relation, &input, seed).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_relations_on_generated() {
+        for relation in RELATIONS {
+            let day = days::find(relation.day).unwrap();
+            for seed in 0..5 {
+                let mut input = (day.generator.generate)(&mut ChaCha8Rng::seed_from_u64(seed), day.generator.typical_size.min(12));
+                // Generated grids are square, which hides rows and columns
+                // getting mixed up.
+                if day.shape == Shape::Grid {
+                    input = input.lines().take(8).map(|line| format!("{line}\n")).collect();
+                }
+                check(relation, &input, seed).unwrap();
+            }
+        }
+    }
+}
@@ -0,0 +1,266 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+use std::time::Duration;
+
+use crate::days::{Day, Part, Strategy};
+use crate::runner::{self, RunResult};
+
+// Shrinks a failing input by delta debugging: cut away pieces of it for as
+// long as it keeps failing the same way. Pieces are cut along each day's
+// structure, so every smaller input is still one the day can parse.
+
+// How long each smaller input gets to fail in, when nobody says otherwise.
+pub const ATTEMPT_BUDGET: Duration = Duration::from_secs(5);
+
+// How a day's input is laid out, and so which pieces of it can go.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Shape {
+    // Any line can go.
+    Lines,
+    // The first lines stay (day8's instructions), any line after them can go.
+    Header(usize),
+    // Blank line separated sections whose first line stays (day5's
+    // `seed-to-soil map:`), any other line can go.
+    Sections,
+    // `Label: a b c` lines where a whole column of values can go (day6).
+    Columns,
+    // Whole rows or columns of a rectangular grid can go.
+    Grid,
+}
+
+// What makes an input fail.
+#[derive(Clone, Copy, Debug)]
+pub enum Failure {
+    // The strategy panics, in the same place it first did.
+    Panic(&'static Strategy),
+    // The part's strategies don't all give the same answer.
+    Disagree,
+}
+
+// Puts an input back together from the pieces kept.
+type Render = Box<dyn Fn(&[usize]) -> String>;
+
+// One way of cutting down an input, into some number of pieces that can
+// each be left out.
+struct Cut {
+    pieces: usize,
+    render: Render,
+}
+
+fn joined(lines: impl IntoIterator<Item = String>) -> String {
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+fn owned(input: &str) -> Vec<String> {
+    input.lines().map(String::from).collect()
+}
+
+// The ways to cut down an input of a given shape, tried one after another.
+fn cuts(shape: Shape, input: &str) -> Vec<Cut> {
+    match shape {
+        Shape::Lines => cuts(Shape::Header(0), input),
+        Shape::Header(keep) => {
+            let lines = owned(input);
+            let keep = keep.min(lines.len());
+            vec!(Cut {
+                pieces: lines.len() - keep,
+                render: Box::new(move |kept| {
+                    joined(lines[..keep].iter().cloned().chain(kept.iter().map(|idx| lines[keep + idx].clone())))
+                }),
+            })
+        },
+        Shape::Sections => {
+            let sections: Vec<Vec<String>> = input.split("\n\n").map(owned).collect();
+            // Every line but the first of each section, as (section, line).
+            let pieces: Vec<(usize, usize)> = sections.iter().enumerate()
+              .flat_map(|(section, lines)| (1..lines.len()).map(move |line| (section, line)))
+              .collect();
+            vec!(Cut {
+                pieces: pieces.len(),
+                render: Box::new(move |kept| {
+                    let sections: Vec<String> = sections.iter().enumerate().map(|(section, lines)| {
+                        let body = kept.iter().map(|idx| pieces[*idx]).filter(|(s, _)| *s == section);
+                        joined(lines.first().cloned().into_iter().chain(body.map(|(_, line)| lines[line].clone())))
+                    }).collect();
+                    sections.join("\n")
+                }),
+            })
+        },
+        Shape::Columns => {
+            let rows: Vec<(String, Vec<String>)> = input.lines().map(|line| {
+                let (label, values) = line.split_once(':').unwrap_or(("", line));
+                (format!("{label}:"), values.split_whitespace().map(String::from).collect())
+            }).collect();
+            vec!(Cut {
+                pieces: rows.iter().map(|(_, values)| values.len()).min().unwrap_or(0),
+                render: Box::new(move |kept| {
+                    joined(rows.iter().map(|(label, values)| {
+                        kept.iter().fold(label.clone(), |line, idx| line + " " + &values[*idx])
+                    }))
+                }),
+            })
+        },
+        Shape::Grid => {
+            let grid: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+            let cols = grid.first().map_or(0, Vec::len);
+            let by_rows = grid.clone();
+            vec!(
+                Cut {
+                    pieces: grid.len(),
+                    render: Box::new(move |kept| joined(kept.iter().map(|row| by_rows[*row].iter().collect()))),
+                },
+                Cut {
+                    pieces: cols,
+                    render: Box::new(move |kept| joined(grid.iter().map(|row| kept.iter().map(|col| row[*col]).collect()))),
+                },
+            )
+        },
+    }
+}
+
+// Zeller's ddmin: the smallest subset of `items` we can find that still
+// fails, first trying each of `n` chunks alone, then everything but each
+// chunk, then smaller chunks.
+pub fn ddmin<T: Clone>(mut items: Vec<T>, mut fails: impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut n = 2;
+    while items.len() >= 2 {
+        let size = items.len().div_ceil(n);
+        let chunks: Vec<Vec<T>> = items.chunks(size).map(<[T]>::to_vec).collect();
+        if let Some(chunk) = chunks.iter().find(|chunk| fails(chunk)) {
+            items = chunk.clone();
+            n = 2;
+            continue;
+        }
+        let complements = (0..chunks.len()).map(|skip| {
+            chunks.iter().enumerate().filter(|(idx, _)| *idx != skip).flat_map(|(_, chunk)| chunk.clone()).collect::<Vec<T>>()
+        });
+        if let Some(complement) = complements.into_iter().find(|complement| fails(complement)) {
+            items = complement;
+            n = (n - 1).max(2);
+            continue;
+        }
+        if n >= items.len() {
+            break;
+        }
+        n = (n * 2).min(items.len());
+    }
+    items
+}
+
+// Whether `input` fails, trying it in a child `aoc run` like the dashboard
+// does, so solvers that panic or never finish can't take us down with them.
+// Solvers that run out of time haven't failed.
+struct Attempt {
+    day: &'static Day,
+    part: &'static Part,
+    budget: Duration,
+    path: PathBuf,
+}
+
+impl Attempt {
+    fn run(&self, strategy: &Strategy, input: &str) -> Result<RunResult, String> {
+        fs::write(&self.path, input).map_err(|e| format!("Unable to write {}: {e}", self.path.display()))?;
+        runner::run_strategy_isolated(self.day, self.part, strategy, &self.path, self.budget)
+    }
+
+    // Where the strategy panicked on `input`, if it did.
+    fn panics_at(&self, strategy: &Strategy, input: &str) -> Result<Option<String>, String> {
+        let result = self.run(strategy, input)?;
+        Ok(runner::panic_site(&result.output).filter(|_| !result.success).map(String::from))
+    }
+
+    // Whether every strategy answered, but not all the same.
+    fn disagrees(&self, input: &str) -> Result<bool, String> {
+        let mut answers = Vec::new();
+        for strategy in self.part.strategies {
+            match self.run(strategy, input)?.answer {
+                Some(answer) => answers.push(answer),
+                None => return Ok(false),
+            }
+        }
+        Ok(answers.windows(2).any(|pair| pair[0] != pair[1]))
+    }
+}
+
+// Shrinks a part's `input` while it keeps failing, giving each attempt
+// `budget` to run. Fails when the input doesn't fail to begin with.
+pub fn minimize(day: &'static Day, part: &'static Part, failure: Failure, input: &str, budget: Duration) -> Result<String, String> {
+    let path = env::temp_dir().join(format!("aoc-minimize-{}.txt", process::id()));
+    let attempt = Attempt { day, part, budget, path };
+    // Trouble running an attempt at all counts as it not failing.
+    let result = match failure {
+        Failure::Panic(strategy) => match attempt.panics_at(strategy, input)? {
+            Some(site) => {
+                eprintln!("{} panicked at {site}", strategy.name);
+                minimize_with(day.shape, input, |input| attempt.panics_at(strategy, input).is_ok_and(|at| at == Some(site.clone())))
+            },
+            None => Err(format!("{} doesn't panic on this input", strategy.name)),
+        },
+        Failure::Disagree => minimize_with(day.shape, input, |input| attempt.disagrees(input).unwrap_or(false)),
+    };
+    let _ = fs::remove_file(&attempt.path);
+    result
+}
+
+// Shrinks `input` with `fails` saying whether a smaller input still fails.
+pub fn minimize_with(shape: Shape, input: &str, fails: impl Fn(&str) -> bool) -> Result<String, String> {
+    if !fails(input) {
+        return Err(String::from("The input doesn't fail to begin with"));
+    }
+    let mut current = String::from(input);
+    loop {
+        let before = current.len();
+        for idx in 0..cuts(shape, &current).len() {
+            let cut = cuts(shape, &current).swap_remove(idx);
+            let kept = ddmin((0..cut.pieces).collect(), |kept| fails(&(cut.render)(kept)));
+            // Rendering everything may tidy the input up without it still
+            // failing, so only take a cut that left something out.
+            if kept.len() < cut.pieces {
+                current = (cut.render)(&kept);
+            }
+        }
+        if current.len() == before {
+            return Ok(current);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::minimize::*;
+
+    fn render(shape: Shape, input: &str, kept: &[usize]) -> Vec<String> {
+        cuts(shape, input).iter().map(|cut| (cut.render)(kept)).collect()
+    }
+
+    #[test]
+    fn test_ddmin() {
+        let items: Vec<u32> = (0..20).collect();
+        assert_eq!(ddmin(items.clone(), |kept| kept.contains(&3) && kept.contains(&17)), vec!(3, 17));
+        assert_eq!(ddmin(items, |kept| kept.contains(&8)), vec!(8));
+    }
+
+    #[test]
+    fn test_cuts() {
+        assert_eq!(render(Shape::Header(2), "LR\n\nAAA\nBBB\nZZZ\n", &[1]), vec!("LR\n\nBBB\n"));
+        assert_eq!(render(Shape::Sections, "seeds: 1 2\n\na map:\n1 2 3\n4 5 6\n\nb map:\n7 8 9\n", &[1]),
+          vec!("seeds: 1 2\n\na map:\n4 5 6\n\nb map:\n"));
+        assert_eq!(render(Shape::Columns, "Time:      7  15   30\nDistance:  9  40  200\n", &[0, 2]),
+          vec!("Time: 7 30\nDistance: 9 200\n"));
+        assert_eq!(render(Shape::Grid, "abc\ndef\nghi\n", &[0, 2]), vec!("abc\nghi\n", "ac\ndf\ngi\n"));
+    }
+
+    #[test]
+    fn test_minimize_with() {
+        // Fails while there is an 'x' in the grid, which stays rectangular.
+        let grid = "....\n.x..\n....\n";
+        assert_eq!(minimize_with(Shape::Grid, grid, |input| input.contains('x')), Ok(String::from("x\n")));
+        assert!(minimize_with(Shape::Grid, grid, |input| input.contains('y')).is_err());
+        // Section headers stay, even once their section is empty.
+        let almanac = "seeds: 1 2\n\na map:\n1 2 3\n4 5 6\n\nb map:\n7 8 9\n";
+        assert_eq!(minimize_with(Shape::Sections, almanac, |input| input.contains("5 6")),
+          Ok(String::from("seeds: 1 2\n\na map:\n4 5 6\n\nb map:\n")));
+    }
+}
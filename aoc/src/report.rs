@@ -0,0 +1,141 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::answers::{Answers, Verdict};
+use crate::days::{Day, InputKind, Part, DAYS};
+use crate::runner::{self, RunResult, RunStatus};
+
+// A single HTML page with every day's answers and pictures, which needs
+// nothing but a browser to read.
+
+// How every part of every day did.
+pub struct Row {
+    pub day: &'static Day,
+    pub part: &'static Part,
+    pub result: Result<RunResult, String>,
+}
+
+// A day's picture, or why there isn't one.
+pub struct Picture {
+    pub day: &'static Day,
+    pub svg: Result<String, String>,
+}
+
+const STYLE: &str = "\
+body { background: #0f0f23; color: #cccccc; font-family: monospace; margin: 2em; }
+h1, h2 { color: #00cc00; }
+table { border-collapse: collapse; }
+th, td { padding: 0.2em 1em; text-align: left; }
+th { border-bottom: 1px solid #666666; }
+.ok { color: #00cc00; }
+.unrecorded { color: #888888; }
+.bad { color: #ff4444; }
+";
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+// Solves every part in a child process, like the dashboard does.
+pub fn run_all(kind: InputKind, budget: Duration) -> Vec<Row> {
+    DAYS.iter().flat_map(|day| day.parts.iter().map(move |part| (day, part))).map(|(day, part)| {
+        let result = runner::run_isolated(day, part, &day.input_path(kind, part.number), budget);
+        Row { day, part, result }
+    }).collect()
+}
+
+// Draws every day that has a visualization, giving up on any that run out
+// of time.
+pub fn draw_all(kind: InputKind, budget: Duration) -> Vec<Picture> {
+    DAYS.iter().filter_map(|day| day.visualization.map(|visualize| (day, visualize))).map(|(day, visualize)| {
+        let svg = runner::read_input(day, kind, 1).and_then(|input| {
+            runner::within(budget, move || visualize(&input), |_, _| ())
+              .map_err(|timed_out| format!("Ran out of time after {:.3}s", timed_out.elapsed.as_secs_f64()))
+        });
+        Picture { day, svg }
+    }).collect()
+}
+
+fn row_html(row: &Row, answers: &Answers, kind: InputKind) -> String {
+    let (answer, time, class, status) = match &row.result {
+        Ok(result) => {
+            let status = result.status(answers, row.day, row.part, kind);
+            let class = match status {
+                RunStatus::Answered(Verdict::Correct) => "ok",
+                RunStatus::Answered(Verdict::Unrecorded) => "unrecorded",
+                _ => "bad",
+            };
+            let time = format!("{:.3}ms", result.elapsed.as_secs_f64() * 1000.0);
+            (result.answer.clone().unwrap_or_default(), time, class, status.to_string())
+        },
+        Err(message) => (String::new(), String::new(), "bad", message.clone()),
+    };
+    format!("<tr><td>{}</td><td>{}</td><td>{}</td><td>{time}</td><td class=\"{class}\">{}</td></tr>\n",
+      row.day.name, row.part.number, escape(&answer), escape(&status))
+}
+
+pub fn html(kind: InputKind, rows: &[Row], pictures: &[Picture], answers: &Answers) -> String {
+    let mut html = String::new();
+    write!(html, "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+                  <title>Advent of Code 2023</title>\n<style>\n{STYLE}</style>\n</head>\n<body>\n\
+                  <h1>Advent of Code 2023</h1>\n<p>Every day, run against its {kind} input.</p>\n\
+                  <table>\n<tr><th>Day</th><th>Part</th><th>Answer</th><th>Time</th><th>Status</th></tr>\n").unwrap();
+    for row in rows {
+        html += &row_html(row, answers, kind);
+    }
+    html += "</table>\n";
+    for picture in pictures {
+        writeln!(html, "<h2>{}</h2>", picture.day.name).unwrap();
+        match &picture.svg {
+            Ok(svg) => writeln!(html, "{svg}").unwrap(),
+            Err(message) => writeln!(html, "<p class=\"bad\">{}</p>", escape(message)).unwrap(),
+        }
+    }
+    html += "</body>\n</html>\n";
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days;
+    use crate::report::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("<b>\"1 & 2\"</b>"), "&lt;b&gt;&quot;1 &amp; 2&quot;&lt;/b&gt;");
+    }
+
+    #[test]
+    fn test_html() {
+        let day = days::find("day10").unwrap();
+        let result = RunResult {
+            answer: Some(String::from("8")),
+            elapsed: Duration::from_micros(1500),
+            output: String::new(),
+            success: true,
+            timed_out: None,
+        };
+        let rows = vec!(
+            Row { day, part: &day.parts[0], result: Ok(result) },
+            Row { day, part: &day.parts[0], result: Err(String::from("Missing input: <none>")) },
+        );
+        let pictures = vec!(
+            Picture { day, svg: Ok(String::from("<svg></svg>")) },
+            Picture { day, svg: Err(String::from("Ran out of time after 1.000s")) },
+        );
+        let answers = Answers::parse("day10 1 example 8\n").unwrap();
+        let html = html(InputKind::Example, &rows, &pictures, &answers);
+        assert!(html.contains("<tr><td>day10</td><td>1</td><td>8</td><td>1.500ms</td><td class=\"ok\">ok</td></tr>\n"));
+        assert!(html.contains("<td class=\"bad\">Missing input: &lt;none&gt;</td>"));
+        assert!(html.contains("<h2>day10</h2>\n<svg></svg>\n"));
+        assert!(html.contains("<p class=\"bad\">Ran out of time after 1.000s</p>"));
+        assert!(html.ends_with("</html>\n"));
+    }
+
+    #[test]
+    fn test_draw_all() {
+        let pictures = draw_all(InputKind::Example, Duration::from_secs(10));
+        assert_eq!(pictures.iter().map(|p| p.day.name).collect::<Vec<_>>(), vec!("day10", "day11", "day16"));
+        assert!(pictures.iter().all(|p| p.svg.as_ref().is_ok_and(|svg| svg.starts_with("<svg"))));
+    }
+}
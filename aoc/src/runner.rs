@@ -0,0 +1,303 @@
+use nom::IResult;
+use nom::bytes::complete::tag;
+use nom::bytes::complete::take_until;
+use nom::character::complete::{alphanumeric1, u8};
+use nom::number::complete::double;
+use nom::sequence::tuple;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::panic;
+use std::process::Command;
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+use watchdog::{Progress, Watch};
+
+use crate::answers::{Answers, Verdict};
+use crate::days::{Day, InputKind, Part, Strategy};
+
+// Everything we learned from running a part once.
+#[derive(Clone, Debug)]
+pub struct RunResult {
+    pub answer: Option<String>,
+    pub elapsed: Duration,
+    // Everything the solver printed, for debugging.
+    pub output: String,
+    pub success: bool,
+    // The stage a solver was stuck in when it ran out of time.
+    pub timed_out: Option<String>,
+}
+
+// How a run finished, as far as anyone looking at the results cares.
+#[derive(Debug, PartialEq)]
+pub enum RunStatus {
+    Answered(Verdict),
+    TimedOut(String),
+    Crashed,
+    NoAnswer,
+}
+
+impl RunResult {
+    pub fn status(&self, answers: &Answers, day: &Day, part: &Part, kind: InputKind) -> RunStatus {
+        match &self.answer {
+            Some(answer) => RunStatus::Answered(answers.check(day.name, part.number, kind, answer)),
+            None => self.unanswered(),
+        }
+    }
+
+    // The status against a single expected answer, rather than the recorded ones.
+    pub fn status_against(&self, expected: Option<&String>) -> RunStatus {
+        match &self.answer {
+            Some(answer) => RunStatus::Answered(Verdict::of(expected, answer)),
+            None => self.unanswered(),
+        }
+    }
+
+    fn unanswered(&self) -> RunStatus {
+        match &self.timed_out {
+            Some(stage) => RunStatus::TimedOut(stage.clone()),
+            None if !self.success => RunStatus::Crashed,
+            None => RunStatus::NoAnswer,
+        }
+    }
+}
+
+impl fmt::Display for RunStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RunStatus::Answered(Verdict::Correct) => write!(f, "ok"),
+            RunStatus::Answered(Verdict::Wrong { expected }) => write!(f, "expected {expected}"),
+            RunStatus::Answered(Verdict::Unrecorded) => write!(f, "unrecorded"),
+            RunStatus::TimedOut(stage) => write!(f, "timed out in {stage}"),
+            RunStatus::Crashed => write!(f, "crashed"),
+            RunStatus::NoAnswer => write!(f, "no answer"),
+        }
+    }
+}
+
+// A solver that was still going when its budget ran out.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimedOut {
+    pub stage: Option<String>,
+    pub elapsed: Duration,
+}
+
+// How long a part may run when nobody says otherwise.
+pub const DEFAULT_BUDGET: Duration = Duration::from_secs(60);
+// How often a solver under a budget reports its progress.
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(1);
+// How long a cancelled solver gets to reach a checkpoint.
+const CANCEL_GRACE: Duration = Duration::from_millis(500);
+
+// Solves a part in this process, timing just the solver.
+pub fn solve(strategy: &Strategy, input: &str) -> (String, Duration) {
+    let start = Instant::now();
+    let answer = (strategy.solve)(input);
+    (answer, start.elapsed())
+}
+
+// Solves a part with `strategy` on its own thread, cancelling it once
+// `budget` runs out.
+//
+// Every so often `report` hears how far the solver got. A solver that
+// panics panics here too.
+pub fn solve_within(
+  strategy: &'static Strategy,
+  input: String,
+  budget: Duration,
+  report: impl FnMut(&Progress, Duration),
+) -> Result<(String, Duration), TimedOut> {
+    within(budget, move || solve(strategy, &input), report)
+}
+
+// Runs any work under a watch on its own thread, like `solve_within`.
+pub fn within<R: Send + 'static>(
+  budget: Duration,
+  work: impl FnOnce() -> R + Send + 'static,
+  mut report: impl FnMut(&Progress, Duration),
+) -> Result<R, TimedOut> {
+    let watch = Watch::new();
+    let worker = watch.clone();
+    let (sender, receiver) = channel();
+    let start = Instant::now();
+    let handle = thread::spawn(move || {
+        let _ = sender.send(worker.run(work));
+    });
+    let stage = loop {
+        let elapsed = start.elapsed();
+        if elapsed >= budget {
+            watch.cancel();
+            // Work that never checks in can't tell us where it is.
+            break match receiver.recv_timeout(CANCEL_GRACE) {
+                Ok(Ok(result)) => return Ok(result),
+                Ok(Err(cancelled)) => cancelled.stage,
+                Err(_) => watch.progress().stage,
+            };
+        }
+        match receiver.recv_timeout(PROGRESS_INTERVAL.min(budget - elapsed)) {
+            Ok(Ok(result)) => return Ok(result),
+            Ok(Err(cancelled)) => break cancelled.stage,
+            Err(RecvTimeoutError::Timeout) if start.elapsed() < budget => report(&watch.progress(), start.elapsed()),
+            Err(RecvTimeoutError::Timeout) => (),
+            Err(RecvTimeoutError::Disconnected) => match handle.join() {
+                Err(payload) => panic::resume_unwind(payload),
+                Ok(()) => unreachable!("work finished without a result"),
+            },
+        }
+    };
+    Err(TimedOut { stage, elapsed: start.elapsed() })
+}
+
+// The line `aoc run` prints for every part it solves.
+pub fn summary(day: &Day, part: &Part, answer: &str, elapsed: Duration) -> String {
+    format!("{} part {}: {answer} ({:.3}ms)", day.name, part.number, elapsed.as_secs_f64() * 1000.0)
+}
+
+// The line `aoc run` prints for a part that ran out of time.
+pub fn timeout_summary(day: &Day, part: &Part, timed_out: &TimedOut) -> String {
+    let stage = timed_out.stage.as_deref().unwrap_or("an unknown stage");
+    format!("{} part {}: timed out in {stage} after {:.3}s", day.name, part.number, timed_out.elapsed.as_secs_f64())
+}
+
+// day8 part 1: timed out in walk to ZZZ after 5.000s
+fn timeout_line(input: &str) -> IResult<&str, String> {
+    let (input, (_, _, _, _, stage, _)) = tuple((
+        alphanumeric1, tag(" part "), u8, tag(": timed out in "),
+        take_until(" after "), tag(" after "),
+    ))(input)?;
+    Ok((input, String::from(stage)))
+}
+
+// dayone part 2: 281 (0.123ms)
+fn summary_line(input: &str) -> IResult<&str, (String, Duration)> {
+    let (input, (_, _, _, _, answer, _, millis, _)) = tuple((
+        alphanumeric1, tag(" part "), u8, tag(": "),
+        take_until(" ("), tag(" ("), double, tag("ms)"),
+    ))(input)?;
+    Ok((input, (String::from(answer), Duration::from_secs_f64(millis / 1000.0))))
+}
+
+// Runs a part in a child `aoc run`, so everything the solver prints can be
+// captured, and a crashing solver can't take the caller down with it.
+pub fn run_isolated(day: &Day, part: &Part, input: &Path, budget: Duration) -> Result<RunResult, String> {
+    run_strategy_isolated(day, part, part.strategy(), input, budget)
+}
+
+// Like `run_isolated`, with any of the part's strategies.
+pub fn run_strategy_isolated(day: &Day, part: &Part, strategy: &Strategy, input: &Path, budget: Duration) -> Result<RunResult, String> {
+    if !input.exists() {
+        return Err(format!("Missing input: {}", input.display()));
+    }
+    let exe = std::env::current_exe().map_err(|e| e.to_string())?;
+    let output = Command::new(exe)
+      .arg("run")
+      .arg(day.name)
+      .arg(part.number.to_string())
+      .arg("--input")
+      .arg(input)
+      .arg("--timeout")
+      .arg(budget.as_secs_f64().to_string())
+      .arg("--strategy")
+      .arg(strategy.name)
+      .output()
+      .map_err(|e| format!("Unable to run {}: {e}", day.name))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let summary =
+      stdout.lines()
+      .rev()
+      .find_map(|line| summary_line(line).ok().map(|(_, summary)| summary));
+    let timed_out = stdout.lines().find_map(|line| timeout_line(line).ok().map(|(_, stage)| stage));
+    let (answer, elapsed) = match summary {
+        Some((answer, elapsed)) => (Some(answer), elapsed),
+        None => (None, Duration::ZERO),
+    };
+    Ok(RunResult {
+        answer,
+        elapsed,
+        output: format!("{stdout}{stderr}"),
+        success: output.status.success(),
+        timed_out,
+    })
+}
+
+// Where a child's solver panicked, from the message Rust prints:
+// thread '<unnamed>' (2199) panicked at day9/src/lib.rs:27:21:
+pub fn panic_site(output: &str) -> Option<&str> {
+    output.lines().filter(|line| line.starts_with("thread '")).find_map(|line| {
+        let (_, site) = line.split_once(" panicked at ")?;
+        Some(site.trim_end_matches(':'))
+    })
+}
+
+pub fn read_input(day: &Day, kind: InputKind, part: u8) -> Result<String, String> {
+    let path = day.input_path(kind, part);
+    fs::read_to_string(&path).map_err(|e| format!("Unable to read {}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::DAYS;
+    use crate::runner::*;
+
+    #[test]
+    fn test_summary_line() {
+        let (day, part) = (&DAYS[0], &DAYS[0].parts[1]);
+        let line = summary(day, part, "281", Duration::from_micros(1500));
+        assert_eq!(line, "dayone part 2: 281 (1.500ms)");
+        let (rest, (answer, elapsed)) = summary_line(&line).unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(answer, "281");
+        assert_eq!(elapsed.as_micros(), 1500);
+        assert!(summary_line("1fooo2 results in 12").is_err());
+    }
+
+    #[test]
+    fn test_timeout_line() {
+        let (day, part) = (&DAYS[6], &DAYS[6].parts[0]);
+        let timed_out = TimedOut { stage: Some(String::from("walk to ZZZ")), elapsed: Duration::from_secs(5) };
+        let line = timeout_summary(day, part, &timed_out);
+        assert_eq!(line, "day8 part 1: timed out in walk to ZZZ after 5.000s");
+        assert_eq!(timeout_line(&line).map(|(_, stage)| stage), Ok(String::from("walk to ZZZ")));
+        assert!(timeout_line("day8 part 1: 2 (0.100ms)").is_err());
+    }
+
+    #[test]
+    fn test_panic_site() {
+        let output = "Calculate diff seq as: []\nthread 'main' panicked at day9/src/lib.rs:12:5:\nattempt to subtract with overflow\n";
+        assert_eq!(panic_site(output), Some("day9/src/lib.rs:12:5"));
+        assert_eq!(panic_site("thread '<unnamed>' (2199) panicked at day9/src/lib.rs:27:21:\n"), Some("day9/src/lib.rs:27:21"));
+        assert_eq!(panic_site("day9 part 1: 114 (0.100ms)\n"), None);
+    }
+
+    #[test]
+    fn test_solve_within() {
+        let walk = DAYS[6].parts[0].strategy();
+        let (answer, _) = solve_within(walk, String::from("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\n"), Duration::from_secs(5), |_, _| ()).unwrap();
+        assert_eq!(answer, "2");
+        // ZZZ is nowhere to be found.
+        let unreachable = String::from("RL\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n");
+        let mut reports = Vec::new();
+        let timed_out = solve_within(walk, unreachable, Duration::from_millis(1200), |progress, _| reports.push(progress.clone())).unwrap_err();
+        assert_eq!(timed_out.stage.as_deref(), Some("walk to ZZZ"));
+        assert!(timed_out.elapsed >= Duration::from_millis(1200));
+        assert_eq!(reports.len(), 1);
+        assert!(reports[0].done > 0);
+    }
+
+    #[test]
+    fn test_examples_match_recorded_answers() {
+        let answers = Answers::load().unwrap();
+        for day in DAYS {
+            for part in day.parts {
+                let input = read_input(day, InputKind::Example, part.number).unwrap();
+                for strategy in part.strategies {
+                    let (answer, _) = solve(strategy, &input);
+                    assert_eq!(answers.check(day.name, part.number, InputKind::Example, &answer), Verdict::Correct,
+                      "{} part {} answered {answer} with {}", day.name, part.number, strategy.name);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use serde_json::{json, Value};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::days::{self, Day, Part, DAYS};
+use crate::runner;
+
+// A small HTTP server on localhost, for front-ends and scripts that want
+// answers without a terminal:
+//
+//   GET  /days   every day, its parts and whether it draws anything
+//   POST /solve  {"day": 16, "input": "...", "part": 1} solves the day's
+//                parts (or just one), with its picture when it has one
+
+// Where the server listens when nobody says otherwise.
+pub const DEFAULT_PORT: u16 = 8023;
+
+// A response before it's sent: the status code and a JSON body.
+pub type Reply = (u16, Value);
+
+fn error(status: u16, message: &str) -> Reply {
+    (status, json!({ "error": message }))
+}
+
+fn list_days() -> Reply {
+    let days: Vec<Value> = DAYS.iter().map(|day| json!({
+        "day": day.name,
+        "number": day.number,
+        "parts": day.parts.iter().map(|part| part.number).collect::<Vec<_>>(),
+        "visualization": day.visualization.is_some(),
+    })).collect();
+    (200, Value::Array(days))
+}
+
+// How many solvers may be running at once. Cancelling one that ran out of
+// time only stops it at its next checkpoint, so one that never checks in
+// keeps its thread spinning after its request gave up on it: these still
+// count, and once there are this many, requests are turned away rather than
+// piling up more of them.
+const MAX_SOLVING: usize = 16;
+
+static SOLVING: AtomicUsize = AtomicUsize::new(0);
+
+// A place among the solvers running at once, given back when dropped.
+struct Solving(&'static AtomicUsize);
+
+impl Solving {
+    fn claim(solving: &'static AtomicUsize, max: usize) -> Option<Solving> {
+        solving.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| (count < max).then_some(count + 1)).ok()?;
+        Some(Solving(solving))
+    }
+}
+
+impl Drop for Solving {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+// Runs `work` under the budget, turning panics and timeouts into messages.
+fn guarded<R: Send + 'static>(budget: Duration, work: impl FnOnce() -> R + Send + 'static) -> Result<R, String> {
+    let solving = Solving::claim(&SOLVING, MAX_SOLVING).ok_or("too many solvers still running, try again later")?;
+    // The place is held by the solver's own thread, for as long as it runs.
+    let work = move || {
+        let _solving = solving;
+        work()
+    };
+    match panic::catch_unwind(AssertUnwindSafe(|| runner::within(budget, work, |_, _| ()))) {
+        Ok(Ok(result)) => Ok(result),
+        Ok(Err(timed_out)) => Err(format!("timed out in {} after {:.3}s",
+          timed_out.stage.as_deref().unwrap_or("an unknown stage"), timed_out.elapsed.as_secs_f64())),
+        Err(_) => Err(String::from("panicked")),
+    }
+}
+
+fn solve_part(part: &'static Part, input: &str, budget: Duration) -> Value {
+    let strategy = part.strategy();
+    let input = String::from(input);
+    match guarded(budget, move || runner::solve(strategy, &input)) {
+        Ok((answer, elapsed)) => json!({ "part": part.number, "answer": answer, "ms": elapsed.as_secs_f64() * 1000.0 }),
+        Err(message) => json!({ "part": part.number, "error": message }),
+    }
+}
+
+// The day named by a request, by number (16) or name ("day16").
+fn requested_day(request: &Value) -> Result<&'static Day, String> {
+    let name = match &request["day"] {
+        Value::Number(number) => number.to_string(),
+        Value::String(name) => name.clone(),
+        Value::Null => return Err(String::from("Missing \"day\"")),
+        other => return Err(format!("Bad \"day\": {other}")),
+    };
+    days::find(&name).ok_or(format!("Unknown day: {name}"))
+}
+
+fn solve(body: &str, budget: Duration) -> Reply {
+    let request: Value = match serde_json::from_str(body) {
+        Ok(request) => request,
+        Err(e) => return error(400, &format!("Bad JSON: {e}")),
+    };
+    let day = match requested_day(&request) {
+        Ok(day) => day,
+        Err(message) => return error(400, &message),
+    };
+    let Some(input) = request["input"].as_str() else {
+        return error(400, "Missing \"input\"");
+    };
+    let parts: Vec<&'static Part> = match &request["part"] {
+        Value::Null => day.parts.iter().collect(),
+        part => match part.as_u64().and_then(|number| day.part(u8::try_from(number).ok()?)) {
+            Some(part) => vec!(part),
+            None => return error(400, &format!("{} has no part {part}", day.name)),
+        },
+    };
+    let mut reply = json!({
+        "day": day.name,
+        "parts": parts.iter().map(|part| solve_part(part, input, budget)).collect::<Vec<_>>(),
+    });
+    if let Some(visualize) = day.visualization {
+        let input = String::from(input);
+        reply["visualization"] = match guarded(budget, move || visualize(&input)) {
+            Ok(svg) => Value::String(svg),
+            Err(message) => json!({ "error": message }),
+        };
+    }
+    (200, reply)
+}
+
+// Answers a request, without touching the network.
+pub fn handle(method: &Method, url: &str, body: &str, budget: Duration) -> Reply {
+    let path = url.split('?').next().unwrap_or(url);
+    match (method, path) {
+        (Method::Get, "/days") => list_days(),
+        (Method::Post, "/solve") => solve(body, budget),
+        (_, "/days" | "/solve") => error(405, &format!("{method} isn't allowed on {path}")),
+        _ => error(404, &format!("Nothing at {path}")),
+    }
+}
+
+fn respond(mut request: Request, budget: Duration) {
+    let mut body = String::new();
+    let (status, reply) = match request.as_reader().read_to_string(&mut body) {
+        Ok(_) => handle(request.method(), request.url(), &body, budget),
+        Err(e) => error(400, &format!("Unable to read the request: {e}")),
+    };
+    let header = Header::from_bytes("Content-Type", "application/json").unwrap();
+    let response = Response::from_string(reply.to_string()).with_status_code(status).with_header(header);
+    if let Err(e) = request.respond(response) {
+        eprintln!("Unable to respond: {e}");
+    }
+}
+
+// Serves requests on localhost until killed, each on its own thread.
+pub fn run(port: u16, budget: Duration) -> Result<(), String> {
+    let server = Server::http(("127.0.0.1", port)).map_err(|e| format!("Unable to listen on port {port}: {e}"))?;
+    println!("Listening on http://127.0.0.1:{port}");
+    for request in server.incoming_requests() {
+        thread::spawn(move || respond(request, budget));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::serve::*;
+
+    const BUDGET: Duration = Duration::from_secs(10);
+
+    #[test]
+    fn test_days() {
+        let (status, days) = handle(&Method::Get, "/days", "", BUDGET);
+        assert_eq!(status, 200);
+        assert_eq!(days[0], json!({ "day": "dayone", "number": 1, "parts": [1, 2], "visualization": false }));
+    }
+
+    #[test]
+    fn test_solve() {
+        let body = json!({ "day": 6, "input": "Time:      7  15   30\nDistance:  9  40  200\n" }).to_string();
+        let (status, reply) = handle(&Method::Post, "/solve", &body, BUDGET);
+        assert_eq!(status, 200);
+        assert_eq!(reply["day"], "day6");
+        assert_eq!(reply["parts"][0]["answer"], "288");
+        assert!(reply.get("visualization").is_none());
+    }
+
+    #[test]
+    fn test_solve_grid() {
+        let body = json!({ "day": "day16", "part": 1, "input": ".|.\n...\n.-.\n" }).to_string();
+        let (status, reply) = handle(&Method::Post, "/solve", &body, BUDGET);
+        assert_eq!(status, 200);
+        assert_eq!(reply["parts"][0]["answer"], "6");
+        assert!(reply["visualization"].as_str().unwrap().starts_with("<svg"));
+    }
+
+    #[test]
+    fn test_solver_panics() {
+        // day9 can't extrapolate a history with no values.
+        let body = json!({ "day": 9, "input": "\n" }).to_string();
+        let (status, reply) = handle(&Method::Post, "/solve", &body, BUDGET);
+        assert_eq!(status, 200);
+        assert_eq!(reply["parts"][0], json!({ "part": 1, "error": "panicked" }));
+    }
+
+    #[test]
+    fn test_solving_limit() {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        let first = Solving::claim(&COUNT, 2).unwrap();
+        let second = Solving::claim(&COUNT, 2).unwrap();
+        assert!(Solving::claim(&COUNT, 2).is_none());
+        drop(first);
+        assert!(Solving::claim(&COUNT, 2).is_some());
+        drop(second);
+        assert_eq!(COUNT.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_bad_requests() {
+        assert_eq!(handle(&Method::Post, "/solve", "{", BUDGET).0, 400);
+        assert_eq!(handle(&Method::Post, "/solve", r#"{"day": 3, "input": ""}"#, BUDGET).1["error"], "Unknown day: 3");
+        assert_eq!(handle(&Method::Post, "/solve", r#"{"day": 6}"#, BUDGET).1["error"], "Missing \"input\"");
+        assert_eq!(handle(&Method::Post, "/solve", r#"{"day": 6, "part": 2, "input": ""}"#, BUDGET).1["error"], "day6 has no part 2");
+        assert_eq!(handle(&Method::Get, "/solve", "", BUDGET).0, 405);
+        assert_eq!(handle(&Method::Get, "/", "", BUDGET).0, 404);
+    }
+}
@@ -0,0 +1,169 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use day10::Location;
+use day11::grid::Grid as GalaxyGrid;
+use day16::grid::Grid;
+
+// Pictures of a day's puzzle, drawn as SVG on a grid where every tile is
+// one unit square.
+
+// How big a tile is drawn.
+const TILE: usize = 6;
+
+fn svg(cols: usize, rows: usize, body: &str) -> String {
+    format!("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {cols} {rows}\">\
+             <rect width=\"{cols}\" height=\"{rows}\" fill=\"#0f0f23\"/>{body}</svg>",
+      cols * TILE, rows * TILE)
+}
+
+fn size(input: &str) -> (usize, usize) {
+    (input.lines().map(|line| line.chars().count()).max().unwrap_or(0), input.lines().count())
+}
+
+// Path segments from the middle of a tile out to each of `ends`.
+fn spokes(path: &mut String, from: Location, ends: &[Location]) {
+    for end in ends {
+        let (x, y) = (from.x() as f64 + 0.5, from.y() as f64 + 0.5);
+        let (dx, dy) = ((end.x() - from.x()) as f64 / 2.0, (end.y() - from.y()) as f64 / 2.0);
+        write!(path, "M{x} {y}l{dx} {dy}").unwrap();
+    }
+}
+
+// day10: the loop through S, with the tiles farthest along it marked, among
+// every stray pipe.
+pub fn pipe_loop(input: &str) -> String {
+    let nodes = day10::parse_input(input);
+    let distances = day10::loop_distances(&nodes);
+    let farthest = distances.values().copied().max().unwrap_or(0);
+    let (mut loop_path, mut stray_path, mut marks) = (String::new(), String::new(), String::new());
+    for node in &nodes {
+        match distances.get(&node.id) {
+            Some(0) => {
+                let ends: Vec<Location> =
+                  [node.id.north(), node.id.south(), node.id.east(), node.id.west()].into_iter()
+                  .filter(|end| distances.get(end) == Some(&1))
+                  .collect();
+                spokes(&mut loop_path, node.id, &ends);
+                write!(marks, "<circle cx=\"{}.5\" cy=\"{}.5\" r=\"0.45\" fill=\"#00cc00\"/>", node.id.x(), node.id.y()).unwrap();
+            },
+            Some(distance) => {
+                spokes(&mut loop_path, node.id, &node.connections());
+                if *distance == farthest {
+                    write!(marks, "<circle cx=\"{}.5\" cy=\"{}.5\" r=\"0.45\" fill=\"#ff4444\"/>", node.id.x(), node.id.y()).unwrap();
+                }
+            },
+            None => spokes(&mut stray_path, node.id, &node.connections()),
+        }
+    }
+    let (cols, rows) = size(input);
+    svg(cols, rows, &format!(
+      "<path d=\"{stray_path}\" stroke=\"#333340\" stroke-width=\"0.2\"/>\
+       <path d=\"{loop_path}\" stroke=\"#ffff66\" stroke-width=\"0.3\"/>{marks}"))
+}
+
+// Where each row (or column) lands once the empty ones have doubled.
+fn expanded_positions(count: usize, expanded: &[usize]) -> Vec<usize> {
+    (0..count).scan(0, |position, idx| {
+        let at = *position;
+        *position += if expanded.contains(&idx) { 2 } else { 1 };
+        Some(at)
+    }).collect()
+}
+
+// day11: the universe after the empty rows and columns have doubled, which
+// are shaded.
+pub fn expanded_galaxies(input: &str) -> String {
+    let grid: GalaxyGrid = input.into();
+    let (expanded_rows, expanded_cols) = day11::find_expanded_rows_cols(&grid);
+    let (cols, rows) = size(input);
+    let row_at = expanded_positions(rows, &expanded_rows);
+    let col_at = expanded_positions(cols, &expanded_cols);
+    let (width, height) = (cols + expanded_cols.len(), rows + expanded_rows.len());
+    let mut body = String::new();
+    for row in &expanded_rows {
+        write!(body, "<rect y=\"{}\" width=\"{width}\" height=\"2\" fill=\"#1f2f4f\"/>", row_at[*row]).unwrap();
+    }
+    for col in &expanded_cols {
+        write!(body, "<rect x=\"{}\" width=\"2\" height=\"{height}\" fill=\"#1f2f4f\"/>", col_at[*col]).unwrap();
+    }
+    for (row, col) in day11::find_galaxies(&grid) {
+        write!(body, "<circle cx=\"{}.5\" cy=\"{}.5\" r=\"0.45\" fill=\"#ffffff\"/>", col_at[col], row_at[row]).unwrap();
+    }
+    svg(width, height, &body)
+}
+
+// day16: the tiles the beams energize, under the mirrors and splitters.
+pub fn energized_tiles(input: &str) -> String {
+    let grid: Grid<char> = input.into();
+    let energized: HashSet<day16::location::Location> = day16::energized_tiles(&grid);
+    // Drawn in reading order, so the same input always draws the same picture.
+    let mut tiles: Vec<_> = energized.into_iter().collect();
+    tiles.sort_by_key(|tile| (tile.y(), tile.x()));
+    let mut body = String::new();
+    for tile in &tiles {
+        write!(body, "<rect x=\"{}\" y=\"{}\" width=\"1\" height=\"1\" fill=\"#ff8800\"/>", tile.x(), tile.y()).unwrap();
+    }
+    let mut mirrors = String::new();
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            let segment = match c {
+                '/' => "m0 1l1 -1",
+                '\\' => "l1 1",
+                '|' => "m0.5 0l0 1",
+                '-' => "m0 0.5l1 0",
+                _ => continue,
+            };
+            write!(mirrors, "M{x} {y}{segment}").unwrap();
+        }
+    }
+    let (cols, rows) = size(input);
+    svg(cols, rows, &format!("{body}<path d=\"{mirrors}\" stroke=\"#cccccc\" stroke-width=\"0.15\"/>"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::{InputKind, DAYS};
+    use crate::runner;
+    use crate::visualize::*;
+
+    #[test]
+    fn test_pipe_loop() {
+        let picture = pipe_loop("..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ...\n");
+        assert!(picture.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"30\" height=\"30\" viewBox=\"0 0 5 5\">"));
+        // S, and the one tile farthest along the loop.
+        assert!(picture.contains("<circle cx=\"0.5\" cy=\"2.5\" r=\"0.45\" fill=\"#00cc00\"/>"));
+        assert!(picture.contains("<circle cx=\"4.5\" cy=\"2.5\" r=\"0.45\" fill=\"#ff4444\"/>"));
+        assert_eq!(picture.matches("<circle").count(), 2);
+        assert!(picture.ends_with("</svg>"));
+    }
+
+    #[test]
+    fn test_expanded_positions() {
+        assert_eq!(expanded_positions(5, &[1, 2]), vec!(0, 1, 3, 5, 6));
+    }
+
+    #[test]
+    fn test_expanded_galaxies() {
+        let picture = expanded_galaxies("#..\n...\n..#\n");
+        assert!(picture.contains("viewBox=\"0 0 4 4\""));
+        assert!(picture.contains("<rect y=\"1\" width=\"4\" height=\"2\" fill=\"#1f2f4f\"/>"));
+        assert!(picture.contains("<circle cx=\"3.5\" cy=\"3.5\" r=\"0.45\" fill=\"#ffffff\"/>"));
+    }
+
+    #[test]
+    fn test_energized_tiles() {
+        let picture = energized_tiles(".|.\n...\n.-.\n");
+        assert_eq!(picture.matches("fill=\"#ff8800\"").count(), 6);
+        assert!(picture.contains("M1 0m0.5 0l0 1M1 2m0 0.5l1 0"));
+    }
+
+    #[test]
+    fn test_example_pictures() {
+        for day in DAYS.iter().filter(|day| day.visualization.is_some()) {
+            let input = runner::read_input(day, InputKind::Example, 1).unwrap();
+            let picture = (day.visualization.unwrap())(&input);
+            snapshot::assert_snapshot!(&format!("{}_picture", day.name), picture + "\n");
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! Day 10: Pipe Maze
+//!
+//! Finds the loop of pipes through the start, and how far along it goes.
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt;
+use itertools::Itertools;
+
+/// A location in the grid.
+///
+/// x - 0 is left, N is right
+/// y - 0 is top, N is bottom
+#[derive(Clone, Copy, Debug, PartialEq, Hash, Eq)]
+pub struct Location {
+    x: i64,
+    y: i64,
+}
+
+impl Location {
+    pub fn x(&self) -> i64 {
+        self.x
+    }
+    pub fn y(&self) -> i64 {
+        self.y
+    }
+    pub fn north(&self) -> Location {
+        Location {
+            x: self.x,
+            y: self.y-1,
+        }
+    }
+    pub fn south(&self) -> Location {
+        Location {
+            x: self.x,
+            y: self.y+1,
+        }
+    }
+    pub fn east(&self) -> Location {
+        Location {
+            x: self.x+1,
+            y: self.y,
+        }
+    }
+    pub fn west(&self) -> Location {
+        Location {
+            x: self.x-1,
+            y: self.y,
+        }
+    }
+    pub fn new(x: i64, y: i64) -> Location {
+        Location { x, y }
+    }
+}
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+/// A tile of the maze: where it is, and the pipe (or ground) on it.
+#[derive(Debug)]
+pub struct Node {
+    pub id: Location,
+    pub name: char,
+}
+
+
+impl Node {
+    pub fn new(id: Location, name: char) -> Node {
+        Node { id, name }
+    }
+    /// The locations this tile's pipe connects to.
+    pub fn connections(&self) -> Vec<Location> {
+        match self.name {
+            '|' => vec!(self.id.north(), self.id.south()),
+            '-' => vec!(self.id.east(), self.id.west()),
+            'L' => vec!(self.id.north(), self.id.east()),
+            'J' => vec!(self.id.north(), self.id.west()),
+            '7' => vec!(self.id.south(), self.id.west()),
+            'F' => vec!(self.id.south(), self.id.east()),
+            _ => vec!(),
+        }
+    }
+}
+
+
+// Walks the loop out from S one BFS layer at a time, telling `on_layer`
+// about each, and returns how many steps every tile of the loop is from S.
+fn walk_loop(nodes: &[Node], mut on_layer: impl FnMut(i64, &[Location])) -> HashMap<Location, i64> {
+    let mut nodemap = HashMap::new();
+    for n in nodes {
+        nodemap.insert(n.id, n);
+    }
+
+    // Put all edges into a data structure (Location -> Set<Location>)
+    let mut edges = HashMap::new();
+    for n in nodes {
+        for c in n.connections() {
+            // Write edges from n->n2 and n2->n
+            // However - we need to reject INVALID edges, because of faulty pipes. 
+            let Some(n2) = nodemap.get(&c) else { continue };
+            match n2.name {
+                'S' => {
+                    // TODO - can we just create any edges to starting node?
+                    edges.entry(n.id).or_insert(HashSet::new()).insert(c);
+                    edges.entry(c).or_insert(HashSet::new()).insert(n.id);
+                },
+                '.' => {
+                    // Ignore ground spaces, no pipes connect.
+                },
+                _ => {
+                    // Check the other side first.
+                    if n2.connections().contains(&n.id) {
+                        edges.entry(n.id).or_insert(HashSet::new()).insert(c);
+                    edges.entry(c).or_insert(HashSet::new()).insert(n.id);
+                    }
+                },
+            }
+        }
+    }
+    let Some(start) = nodes.iter().find(|n| n.name =='S') else { panic!("Could not find starting position S!") };
+    // For every location we write an integer, if not visited, denoting distance from start.
+    let mut colors = HashMap::new();
+    let mut cur_nodes = vec!(start.id);
+    let mut cur_iteration = 0;
+    let mut done = false;
+    while !done {
+        on_layer(cur_iteration, &cur_nodes);
+        for n in cur_nodes.iter().cloned() {
+            colors.insert(n, cur_iteration);
+        }
+        // Figure out next nodes to use:
+        let mut next_nodes: Vec<Location> =
+          cur_nodes.iter()
+          .flat_map(|n| edges.get(n).unwrap().iter())
+        //   .inspect(|edge| {
+        //     let color = colors.get(edge);
+        //     println!("Edge: {edge} has color: {color:?}");
+        //   })
+          // Only visit nodes we haven't seen yet.
+          .filter(|n| !colors.contains_key(n))
+          .copied()
+          .unique()
+          .collect();
+
+        // Update the coloring.
+        done = next_nodes.is_empty();
+        if done {
+            explain::event!("farthest",
+              format!("BFS layer {cur_iteration} reached the farthest pipe at {}", cur_nodes.iter().join(", ")),
+              layer = cur_iteration, nodes = cur_nodes);
+        }
+        cur_iteration += 1;
+        cur_nodes.clear();
+        cur_nodes.append(&mut next_nodes);
+    }
+    colors
+}
+
+/// How many steps along the loop each of its tiles is from S.
+pub fn loop_distances(nodes: &[Node]) -> HashMap<Location, i64> {
+    walk_loop(nodes, |_, _| ())
+}
+
+/// Part one: the number of steps to the point of the loop farthest from the start.
+pub fn solve(nodes: Vec<Node>) -> i64 {
+    let distances = walk_loop(&nodes, |step, layer| {
+        println!("{step} STEP, nodes: {}", layer.len());
+        for n in layer {
+            println!(" - Visiting node: {n}");
+        }
+    });
+    distances.values().copied().max().unwrap()
+}
+
+
+/// Parses the maze into one node per tile.
+pub fn parse_input(input: &str) -> Vec<Node> {
+    let mut result = Vec::new();
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            result.push(Node::new(Location::new(x.try_into().unwrap(),y.try_into().unwrap()), c));
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_location() {
+        let x = Location { x: 10, y: 10 };
+        assert_eq!(x.north(), Location::new(10,9));
+        assert_eq!(x.south(), Location::new(10,11));
+        assert_eq!(x.east(), Location::new(11, 10));
+        assert_eq!(x.west(), Location::new(9, 10));
+    }
+    #[test]
+    fn test_node() {
+        let initial = Location { x: 10, y: 10 };
+        assert_eq!(Node::new(initial, '.').connections(), vec!());
+        // Not sure the value of these tests.
+        assert_eq!(Node::new(initial, '|').connections(), vec!(initial.north(), initial.south()));
+        assert_eq!(Node::new(initial, 'F').connections(), vec!(initial.south(), initial.east()));
+    }
+
+    #[test]
+    fn test_solution() {
+        assert_eq!(solve(parse_input(
+            "..F7.\n\
+             .FJ|.\n\
+             SJ.L7\n\
+             |F--J\n\
+             LJ...")), 8);
+        assert_eq!(solve(parse_input(
+                ".....\n\
+                .S-7.\n\
+                .|.|.\n\
+                .L-J.\n\
+                .....")), 4);
+        // Complicated version with extraneous pipes
+        assert_eq!(solve(parse_input(
+            "-L|F7\n\
+             7S-7|\n\
+             L|7||\n\
+             -L-J|\n\
+             L|-JF")), 4);
+    }
+
+    #[test]
+    fn test_explain_farthest() {
+        let (_, events) = explain::capture(|| solve(parse_input(
+            ".....\n\
+             .S-7.\n\
+             .|.|.\n\
+             .L-J.\n\
+             .....")));
+        assert_eq!(events.last().unwrap().message, "BFS layer 4 reached the farthest pipe at (3,3)");
+    }
+
+    #[test]
+    fn test_loop_distances() {
+        let distances = loop_distances(&parse_input(
+            "-L|F7\n\
+             7S-7|\n\
+             L|7||\n\
+             -L-J|\n\
+             L|-JF"));
+        assert_eq!(distances.len(), 8);
+        assert_eq!(distances.get(&Location::new(1, 1)), Some(&0));
+        assert_eq!(distances.get(&Location::new(3, 3)), Some(&4));
+        assert_eq!(distances.get(&Location::new(0, 0)), None);
+    }
+}
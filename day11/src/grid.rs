@@ -1,6 +1,7 @@
 use std::convert::From;
 use std::fmt;
 
+/// A single character of the galaxy map.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Cell {
     value: char,
@@ -17,6 +18,7 @@ impl From<char> for Cell {
     }
 }
 
+/// A 2D grid of cells, with helper iterators and simple ingestion from strings.
 pub struct Grid {
     cells: Vec<Cell>,
     rows: usize,
@@ -26,7 +28,7 @@ pub struct Grid {
 impl fmt::Display for Grid {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
       // TODO - write
-        write!(f, "{}", "")
+        write!(f, "")
     }
 }
 
@@ -49,7 +51,7 @@ impl Grid {
     self.rows
   }
   pub fn cols(&self) -> usize {
-    self.rows
+    self.cols
   }
   pub fn get(&self, row: usize, col: usize) -> Option<&Cell> {
         if row < self.rows && col < self.cols {
@@ -70,7 +72,7 @@ impl Grid {
         GridRowIterator { grid: self, row, index: 0 }
     }
     pub fn col_iter<'a>(&'a self, col: usize) -> GridColIterator<'a> {
-        GridColIterator { grid: self, col: col, index: 0 }
+        GridColIterator { grid: self, col, index: 0 }
     }
     pub fn iter<'a>(&'a self) -> GridIterator<'a> {
       GridIterator { grid: self, row: 0, col: 0 }
@@ -134,6 +136,7 @@ impl <'a> Iterator for GridIterator<'a> {
   }
 }
 
+#[cfg(test)]
 mod tests {
   use crate::grid::*;
 
@@ -164,6 +167,14 @@ mod tests {
       assert_eq!(row1, vec!('a', 'b', 'c'));
   }
   #[test]
+  fn test_non_square() {
+    let grid: Grid = "abc\ndef".into();
+    assert_eq!(grid.rows(), 2);
+    assert_eq!(grid.cols(), 3);
+    assert!(grid.get(1, 2).is_some());
+    assert!(grid.get(2, 1).is_none());
+  }
+  #[test]
   fn test_grid_iteration() {
     let grid: Grid = "ab\ncd".into();
     assert_eq!(grid.cols(), 2);
@@ -1,8 +1,8 @@
 use std::convert::From;
 use std::fmt;
 
-// An implementation of a 2D matrix/grid.
-// Provides helper iterators, and simple ingestion from strings.
+/// An implementation of a 2D matrix/grid.
+/// Provides helper iterators, and simple ingestion from strings.
 pub struct Grid<T> {
     cells: Vec<T>,
     rows: usize,
@@ -19,10 +19,10 @@ impl <T> fmt::Display for Grid<T> where T: fmt::Display {
           }
           write!(f, "{}", self.get(row, col).unwrap())?
         }
-        write!(f, "|\n")?;
+        writeln!(f, "|")?;
       }
       // TODO - write
-      write!(f, "\n")
+      writeln!(f)
     }
 }
 
@@ -45,7 +45,7 @@ impl <T> Grid<T> {
     self.rows
   }
   pub fn cols(&self) -> usize {
-    self.rows
+    self.cols
   }
   pub fn get(&self, row: usize, col: usize) -> Option<&T> {
         if row < self.rows && col < self.cols {
@@ -66,7 +66,7 @@ impl <T> Grid<T> {
         GridRowIterator { grid: self, row, index: 0 }
     }
     pub fn col_iter<'a>(&'a self, col: usize) -> GridColIterator<'a, T> {
-        GridColIterator { grid: self, col: col, index: 0 }
+        GridColIterator { grid: self, col, index: 0 }
     }
     pub fn iter<'a>(&'a self) -> GridIterator<'a, T> {
       GridIterator { grid: self, row: 0, col: 0 }
@@ -130,6 +130,8 @@ impl <'a, T> Iterator for GridIterator<'a, T> {
   }
 }
 
+#[cfg(test)]
+#[allow(clippy::map_clone)]
 mod tests {
   use crate::grid::*;
 
@@ -160,6 +162,14 @@ mod tests {
       assert_eq!(row1, vec!('a', 'b', 'c'));
   }
   #[test]
+  fn test_non_square() {
+    let grid: Grid<char> = "abc\ndef".into();
+    assert_eq!(grid.rows(), 2);
+    assert_eq!(grid.cols(), 3);
+    assert!(grid.get(1, 2).is_some());
+    assert!(grid.get(2, 1).is_none());
+  }
+  #[test]
   fn test_grid_iteration() {
     let grid: Grid<char> = "ab\ncd".into();
     assert_eq!(grid.cols(), 2);
@@ -172,6 +182,6 @@ mod tests {
   #[test]
   fn test_display() {
     let grid: Grid<char> = "ab\ncd".into();
-    assert_eq!(format!("{}", grid), "|a b|\n|c d|\n\n");
+    snapshot::assert_snapshot!("grid_display", format!("{}", grid));
   }
 }
\ No newline at end of file
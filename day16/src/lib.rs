@@ -0,0 +1,323 @@
+//! Day 16: The Floor Will Be Lava
+//!
+//! Traces beams of light through a grid of mirrors and splitters, and
+//! counts the tiles they energize.
+pub mod grid;
+pub mod location;
+
+use grid::Grid;
+use location::Location;
+use std::collections::{HashMap, HashSet};
+
+/// The direction a beam is travelling.
+#[derive(Clone, Copy, Debug)]
+pub enum Direction {
+    North, South, East, West
+}
+
+/// An instance of a ray-tracing beam.
+#[derive(Clone, Copy, Debug)]
+pub struct Beam {
+    pub location: Location,
+    pub direction: Direction,
+}
+
+use Direction::*;
+
+impl Beam {
+    // Beam is just moving straight forward.
+    fn simple_move(&mut self) {
+        let next_location =
+            match self.direction {
+                North => self.location.north(),
+                South => self.location.south(),
+                East => self.location.east(),
+                West => self.location.west(),
+            };
+        self.location = next_location;
+    }
+    
+    /// Runs the beam to the next site based on what it sees at the
+    /// current site.  Possibly returns a new beam.
+    pub fn next_location(&mut self, node: char) -> Option<Beam> {
+        match node {
+            '.' => {
+                self.simple_move();
+                None
+            },
+            '|' => {
+                match self.direction {
+                    North | South => { self.simple_move(); None },
+                    East | West => {
+                        // Split to North + South
+                        let new_location = self.location.north();
+                        self.location = self.location.south();
+                        self.direction = South;
+                        Some(Beam {
+                            location: new_location,
+                            direction: North,
+                        })
+                    },
+                }
+            },
+            '-' => {
+                match self.direction {
+                    East | West => {self.simple_move(); None },
+                    North | South => {
+                        // Split to east and west.
+                        let new_location = self.location.east();
+                        self.location = self.location.west();
+                        self.direction = West;
+                        Some(Beam {
+                            location: new_location,
+                            direction: East,
+                        })
+                    },
+                }
+            },
+            '\\' => match self.direction {
+                North => {
+                    self.direction = West;
+                    self.simple_move();
+                    None
+                },
+                South => {
+                    self.direction = East;
+                    self.simple_move();
+                    None
+                },
+                East => {
+                    self.direction = South;
+                    self.simple_move();
+                    None
+                },
+                West => {
+                    self.direction = North;
+                    self.simple_move();
+                    None
+                },
+            },
+            '/' => match self.direction {
+                North => {
+                    self.direction = East;
+                    self.simple_move();
+                    None
+                },
+                South => {
+                    self.direction = West;
+                    self.simple_move();
+                    None
+                },
+                East => {
+                    self.direction = North;
+                    self.simple_move();
+                    None
+                },
+                West => {
+                    self.direction = South;
+                    self.simple_move();
+                    None
+                },
+            },
+            _ => None,
+        }
+    }
+}
+
+
+// TODO - probably should have hidden masks behind a struct.
+fn direction_mask(d: Direction) -> i32 {
+    match d {
+        North => 1,
+        South => 2,
+        East => 4,
+        West => 8,
+    }
+}
+
+fn next_mask(flags: i32, d: Direction) -> i32 {
+    flags | direction_mask(d)
+}
+
+fn has_direction(flags: i32, d: Direction) -> bool {
+    direction_mask(d) & flags > 0
+}
+
+/// The beam entering the top left of the grid, heading east.
+pub const START: Beam = Beam {
+    location: Location::new(0, 0),
+    direction: East,
+};
+
+// Walks the beams through the maze from `start`, telling `on_loop` how
+// many beams there are each time round, and returns the directions beams
+// crossed each energized tile in.
+fn trace(grid: &Grid<char>, start: Beam, mut on_loop: impl FnMut(usize, usize)) -> HashMap<Location, i32> {
+    let mut visited = HashMap::new();
+    let mut beams: Vec<Beam> = vec!(start);
+    let mut next_beams: Vec<Beam> = Vec::new();
+    let mut done = beams.is_empty();
+    let mut index = 0;
+    while !done {
+        on_loop(index, beams.len());
+        index += 1;
+        // First mark each beam as visiting its location
+        for b in &beams {
+            visited.entry(b.location).and_modify(|e| {
+                *e = next_mask(*e, b.direction);
+            }).or_insert(direction_mask(b.direction));
+        }
+        // Now we mutate the beams, collecting results for next_beams.
+        for b in beams.iter_mut() {
+            if let Some(c) = grid.get(
+                b.location.y().try_into().unwrap(),
+                b.location.x().try_into().unwrap()) {
+                if let Some(added) = b.next_location(*c) {
+                    next_beams.push(added)
+                }
+            }
+        }
+        // All beams have been moved, now we add and filter beams.
+        beams.extend(next_beams);
+        next_beams = vec!();
+        beams.retain(|b| {
+            let x = b.location.x();
+            let y = b.location.y();
+            (y >= 0 && y < grid.rows().try_into().unwrap()) &&
+            (x >= 0 && x < grid.cols().try_into().unwrap())
+        });
+        // We're done when all beams have either reached locations THEY
+        // visited before, or are off the grid.
+        // We do this by tracking the direction we've traveled on each grid.
+        done = beams.iter().all(|b| {
+            visited.get(&b.location)
+            .map(|flags| has_direction(*flags, b.direction))
+            .unwrap_or(false)
+        });
+    }
+    // Now we check how many nodes were visited.
+    // for (l, v) in visited.iter().sorted_by(|a,b| {
+    //     match Ord::cmp(&a.0.y(), &b.0.y()) {
+    //         std::cmp::Ordering::Equal => Ord::cmp(&a.0.x(), &b.0.x()),
+    //         other => other,
+    //     }
+    // }) {
+    //     println!("{l:?} was visited {v:?}");
+    // }
+
+    explain::event!("energized",
+      format!("{} tiles energized after {index} loops", visited.len()),
+      loops = index, energized = visited.len());
+    visited
+}
+
+/// Every tile the beams energize.
+pub fn energized_tiles(grid: &Grid<char>) -> HashSet<Location> {
+    trace(grid, START, |_, _| ()).into_keys().collect()
+}
+
+/// The grid with every energized tile drawn as `#` and every other tile as
+/// `.`, one line per row.
+pub fn energized_map(grid: &Grid<char>) -> String {
+    let tiles = energized_tiles(grid);
+    (0..grid.rows()).map(|row| {
+        let line: String = grid.row_iter(row).enumerate().map(|(col, _)| {
+            if tiles.contains(&Location::new(col as i64, row as i64)) { '#' } else { '.' }
+        }).collect();
+        line + "\n"
+    }).collect()
+}
+
+/// Walks the beams through the maze, starting from the top left heading
+/// east, and returns how many tiles were energized.
+pub fn raytrace(grid: &Grid<char>) -> usize {
+    trace(grid, START, |index, beams| println!("-- Ray tracing loop {index}, beams: {beams}")).len()
+}
+
+/// Like [`raytrace`], with the beam starting somewhere else.
+pub fn raytrace_from(grid: &Grid<char>, start: Beam) -> usize {
+    trace(grid, start, |_, _| ()).len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_raytrace() {
+        let grid: Grid<char> =
+          ".|...\\....\n\
+           |.-.\\.....\n\
+           .....|-...\n\
+           ........|.\n\
+           ..........\n\
+           .........\\\n\
+           ..../.\\\\..\n\
+           .-.-/..|..\n\
+           .|....-|.\\\n\
+           ..//.|....".into();
+        let result = raytrace(&grid);
+        assert_eq!(result, 46);
+    }
+
+    #[test]
+    fn test_energized_tiles() {
+        let grid: Grid<char> =
+          ".|.\n\
+           ...\n\
+           .-.".into();
+        let tiles = energized_tiles(&grid);
+        // Down through the splitter, then out either side of the other one.
+        assert_eq!(tiles, HashSet::from([
+            Location::new(0, 0), Location::new(1, 0), Location::new(1, 1),
+            Location::new(0, 2), Location::new(1, 2), Location::new(2, 2),
+        ]));
+    }
+
+    #[test]
+    fn test_energized_map() {
+        let grid: Grid<char> = include_str!("../example.txt").into();
+        snapshot::assert_snapshot!("energized_map", energized_map(&grid));
+    }
+
+    #[test]
+    fn test_flags() {
+        assert!(!has_direction(next_mask(0, South), North));
+        assert!(!has_direction(next_mask(0, South), East));
+        assert!(!has_direction(next_mask(0, South), West));
+        assert!(has_direction(next_mask(0, South), South));
+        assert!(!has_direction(next_mask(0, North), South));
+        assert!(!has_direction(next_mask(0, North), East));
+        assert!(!has_direction(next_mask(0, North), West));
+        assert!(has_direction(next_mask(0, North), North));
+        assert!(!has_direction(next_mask(0, East), North));
+        assert!(!has_direction(next_mask(0, East), South));
+        assert!(!has_direction(next_mask(0, East), West));
+        assert!(has_direction(next_mask(0, East), East));
+        assert!(!has_direction(next_mask(0, West), North));
+        assert!(!has_direction(next_mask(0, West), East));
+        assert!(!has_direction(next_mask(0, West), South));
+        assert!(has_direction(next_mask(0, West), West));
+
+        assert!(has_direction(next_mask(next_mask(0, West), East), West));
+        assert!(has_direction(next_mask(next_mask(0, West), East), East));
+        assert!(!has_direction(next_mask(next_mask(0, West), East), North));
+        assert!(!has_direction(next_mask(next_mask(0, West), East), South));
+    }
+
+    #[test]
+    fn test_next_location() {
+        let mut beam = Beam {
+            location: Location::new(5,5),
+            direction: North,
+        };
+
+        let result = beam.next_location('.');
+        assert!(result.is_none());
+        assert_eq!(beam.location, Location::new(5, 4));
+        let result2 = beam.next_location('-');
+        assert!(result2.is_some());
+        assert_eq!(beam.location, Location::new(4,4));
+        assert_eq!(result2.unwrap().location, Location::new(6,4));
+    }
+}
\ No newline at end of file
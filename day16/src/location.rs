@@ -1,11 +1,10 @@
 use std::fmt;
-use std::convert;
 
 
-// A location in the grid.
-//
-// x - 0 is left, N is right
-// y - 0 is top, N is bottom
+/// A location in the grid.
+///
+/// x - 0 is left, N is right
+/// y - 0 is top, N is bottom
 #[derive(Clone, Copy, Debug, PartialEq, Hash, Eq)]
 pub struct Location {
     x: i64,
@@ -43,7 +42,7 @@ impl Location {
             y: self.y,
         }
     }
-    pub fn new(x: i64, y: i64) -> Location {
+    pub const fn new(x: i64, y: i64) -> Location {
         Location { x, y }
     }
 }
@@ -65,8 +64,9 @@ impl From<(usize, usize)> for Location {
     }
 }
 
+#[cfg(test)]
 mod tests {
-  use crate::{grid::*, location::Location};
+  use crate::location::Location;
 
   #[test]
   fn test_tuple_conversion() {
@@ -0,0 +1,175 @@
+//! Day 2: Cube Conundrum
+//!
+//! Parses the record of cube games, and checks which games were possible.
+use nom::{Parser,IResult};
+use nom::error::context;
+use nom::bytes::complete::{tag,tag_no_case};
+use nom::character::complete::{space0, space1, u32};
+use nom::sequence::tuple;
+use nom::combinator::map;
+use nom::multi::separated_list1;
+use nom::branch::alt;
+
+/// The cubes of each color seen in a draw, or needed for a game.
+#[derive(Debug, Default, PartialEq)]
+pub struct ColorResult {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+/// Parses the game number, e.g. `Game 2:`.
+pub fn game(input: &str) -> IResult<&str, u32> {
+    context("game number",
+      tuple((tag_no_case("game"), space1, u32, tag_no_case(":"))).map(|(_, _, id, _)| id)
+    )(input)
+}
+
+/// Parses the count of a single color, e.g. `3 blue`.
+pub fn color_result(input: &str) -> IResult<&str, ColorResult> {
+  let blue_result = tuple((space0, u32, space1, tag_no_case("blue"))).map(|(_,num,_,_)| ColorResult { blue: num, ..Default::default() });
+  let red_result = tuple((space0, u32, space1, tag_no_case("red"))).map(|(_,num,_,_)| ColorResult { red: num, ..Default::default() });
+  let green_result = tuple((space0, u32, space1, tag_no_case("green"))).map(|(_,num,_,_)| ColorResult { green: num, ..Default::default() });
+  context("color result", alt((blue_result, red_result, green_result)))(input)
+}
+
+/// Parses a comma separated draw, e.g. `3 blue, 2 red`.
+pub fn draw_results(input: &str) -> IResult<&str, ColorResult> {
+  map(separated_list1(tag(","), color_result), |v| v.iter().fold(Default::default(), |acc: ColorResult, next| ColorResult {
+    red: acc.red + next.red,
+    blue: acc.blue + next.blue,
+    green: acc.green + next.green,
+  }))(input)
+}
+
+/// Parses a full game line: its number and every draw.
+pub fn game_results(input: &str) -> IResult<&str, (u32, Vec<ColorResult>)> {
+    let all_draws = separated_list1(tag(";"), draw_results);
+    tuple((game, all_draws))(input)
+}
+
+
+// Part 1 Solution
+// The first draw needing more cubes than the bag holds, if any.
+fn impossible_draw(results: &[ColorResult]) -> Option<(usize, &ColorResult)> {
+    results.iter()
+      .enumerate()
+      .find(|(_, result)| !(result.red <= 12 && result.green <= 13 && result.blue <= 14))
+}
+
+/// Part one: sums the ids of games possible with 12 red, 13 green and 14 blue cubes.
+pub fn possible_game_sums(input: &str) -> u32 {
+    input.lines().map(|line| {
+        let (_, result) = game_results(line).unwrap();
+        result
+    })
+    .filter(|(id, results)| match impossible_draw(results) {
+        Some((idx, draw)) => {
+            explain::event!("impossible",
+              format!("game {id} is impossible, draw {} has {} red, {} green, {} blue", idx+1, draw.red, draw.green, draw.blue),
+              game = id, draw = idx+1, red = draw.red, green = draw.green, blue = draw.blue);
+            false
+        },
+        None => {
+            explain::event!("possible", format!("game {id} is possible"), game = id);
+            true
+        },
+    })
+    .map(|(id, _)| id)
+    .sum()
+}
+
+// Part 2 Solutions
+/// The fewest cubes of each color that make every draw possible.
+pub fn min_cubes_required(results: &[ColorResult]) -> ColorResult {
+    ColorResult {
+        red: results.iter().map(|r| r.red).max().unwrap_or(0),
+        green: results.iter().map(|r| r.green).max().unwrap_or(0),
+        blue: results.iter().map(|r| r.blue).max().unwrap_or(0),
+    }
+}
+
+/// Parses a game line into its power: the product of its minimum cubes.
+pub fn game_power(input: &str) -> IResult<&str, u32> {
+    map(
+        map(game_results, |(id, result)| (id, min_cubes_required(&result))),
+        |(id, result)| {
+            let power = result.red*result.green*result.blue;
+            explain::event!("power", format!("game {id} needs {result:?}, power {power}"), game = id, minimum = result, power);
+            power
+        }
+    )(input)
+}
+
+/// Part two: sums the power of every game.
+pub fn total_game_sum(input: &str) -> u32 {
+    input.lines().map(|line| {
+        let (_, result) = game_power(line).unwrap();
+        result
+    }).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn game_parser() {
+        assert_eq!(game("Game 2:"), Ok(("", 2)));
+        assert_eq!(game("Game 10: 3 blue, 2 red"), Ok((" 3 blue, 2 red", 10)));
+        assert_eq!(game("Game  11: 3 blue, 2 red"), Ok((" 3 blue, 2 red", 11)));
+    }
+    #[test]
+    fn color_result_parser() {
+        assert_eq!(color_result("1 red"), Ok(("", ColorResult { red: 1, ..Default::default() })));
+        assert_eq!(color_result("3 blue, 2 red"), Ok((", 2 red", ColorResult { blue: 3, ..Default::default() })));
+        assert_eq!(color_result("4 green, 2 red"), Ok((", 2 red", ColorResult { green: 4, ..Default::default() })));
+    }
+    #[test]
+    fn draw_results_parser() {
+        assert_eq!(draw_results("1 red"), Ok(("", ColorResult { red: 1, ..Default::default() })));
+        assert_eq!(draw_results(" 3 blue, 2 red"), Ok(("", ColorResult { blue: 3, red: 2, ..Default::default() })));
+        assert_eq!(draw_results("4 green, 2 red"), Ok(("", ColorResult { green: 4, red: 2, ..Default::default() })));
+    }
+
+    #[test]
+    fn game_results_parser() {
+        assert_eq!(game_results("Game 10: 3 blue, 2 red; 1 green"), Ok(("", (10, vec!(ColorResult { red:2,blue:3,green:0}, ColorResult{green:1,red:0,blue:0})))));
+    }
+
+    // Part 2 tests
+    #[test]
+    fn test_min_cubes_required() {
+        let (_, (_, tosses)) = game_results("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
+        assert_eq!(min_cubes_required(&tosses), ColorResult { red: 4, green:2, blue:6 });
+    }
+
+    #[test]
+    fn test_total_game_sums() {
+        assert_eq!(total_game_sum("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n\
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\n\
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\n\
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\n\
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"), 2286);
+    }
+
+    // Part 1 tests
+    #[test]
+    fn test_explain_impossible_draw() {
+        let (sum, events) = explain::capture(|| possible_game_sums("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n\
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red"));
+        assert_eq!(sum, 1);
+        assert_eq!(events[0].stage, "possible");
+        assert_eq!(events[1].stage, "impossible");
+        assert_eq!(events[1].message, "game 3 is impossible, draw 1 has 20 red, 8 green, 6 blue");
+    }
+
+    #[test]
+    fn test_possible_game_sums() {
+        assert_eq!(possible_game_sums("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n\
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\n\
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\n\
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\n\
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"), 8);
+    }
+}
\ No newline at end of file
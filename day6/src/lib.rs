@@ -0,0 +1,135 @@
+
+//! Day 6: Wait For It
+//!
+//! Counts the ways to beat the record distance in each boat race.
+use nom::IResult;
+use nom::bytes::complete::tag;
+use nom::character::complete::{i64, line_ending, space0, space1};
+use nom::multi::separated_list1;
+
+/// A race: how long it lasts, and the best distance so far.
+#[derive(Debug, PartialEq)]
+pub struct RaceRecord {
+    pub time: i64,
+    pub distance: i64,
+}
+
+// How a boat moves:
+// Time pushed = millis/second.
+// distance in millis
+// time in second.
+//
+//  (max_time - time_pushed) * time_pushed > distance
+//  max_time*time_pushed - time_pushed^2 > distance
+// -time_pushed^2 + max_time*time_pushed - distance > 0
+
+// max_time +/- sqrt(max_time^2 - 4*time_pushed*distance)/-2*time_pushed
+
+/// How many button hold times beat the record distance.
+pub fn number_of_winning_strategies(record: &RaceRecord) -> u32 {
+    // Brute force method
+    (0..record.time).filter(|time_pushed| (record.time-time_pushed)*time_pushed>record.distance).count().try_into().unwrap()
+}
+
+/// How many button hold times beat the record distance, from the roots of
+/// the quadratic above rather than trying every hold time.
+pub fn number_of_winning_strategies_quadratic(record: &RaceRecord) -> u32 {
+    let time = record.time as f64;
+    let discriminant = time*time - 4.0*record.distance as f64;
+    if discriminant < 0.0 {
+        return 0;
+    }
+    // Only hold times strictly between the roots go further than the record.
+    let root = discriminant.sqrt();
+    let shortest = ((time - root) / 2.0).floor() as i64 + 1;
+    let longest = ((time + root) / 2.0).ceil() as i64 - 1;
+    (longest - shortest + 1).max(0).try_into().unwrap()
+}
+
+fn product_of_ways(records: Vec<RaceRecord>, ways_to_win: fn(&RaceRecord) -> u32) -> u32 {
+    records.iter()
+      .map(|record| {
+        let ways = ways_to_win(record);
+        explain::event!("race",
+          format!("{ways} ways to beat {} in {}", record.distance, record.time),
+          time = record.time, distance = record.distance, ways);
+        ways
+      })
+      .product()
+}
+
+/// Part one: the product of the winning strategies of every race.
+pub fn part_one(records: Vec<RaceRecord>) -> u32 {
+    product_of_ways(records, number_of_winning_strategies)
+}
+
+/// Part one, counting winning strategies with the quadratic formula.
+pub fn part_one_quadratic(records: Vec<RaceRecord>) -> u32 {
+    product_of_ways(records, number_of_winning_strategies_quadratic)
+}
+
+// Time:      7  15   30
+fn parse_numbers<'a>(label: &'static str) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<i64>> {
+    move |input| {
+        let (input, _) = tag(label)(input)?;
+        let (input, _) = space0(input)?;
+        separated_list1(space1, i64)(input)
+    }
+}
+
+/// Parses the race sheet: a line of times and a line of distances.
+pub fn parse_input(input: &str) -> IResult<&str, Vec<RaceRecord>> {
+    let (input, times) = parse_numbers("Time:")(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, distances) = parse_numbers("Distance:")(input)?;
+    let records =
+      times.iter()
+      .zip(distances.iter())
+      .map(|(time, distance)| RaceRecord { time: *time, distance: *distance })
+      .collect();
+    Ok((input, records))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_winning_strat() {
+        assert_eq!(number_of_winning_strategies(&RaceRecord{
+            time: 7,
+            distance: 9,
+        }), 4);
+        assert_eq!(number_of_winning_strategies(&RaceRecord{
+            time: 15,
+            distance: 40,
+        }), 8);
+        assert_eq!(number_of_winning_strategies(&RaceRecord{
+            time: 30,
+            distance: 200,
+        }), 9);
+    }
+
+    #[test]
+    fn test_winning_strat_quadratic() {
+        for time in 0..60 {
+            for distance in 0..(time*time/4 + 2) {
+                let record = RaceRecord { time, distance };
+                assert_eq!(number_of_winning_strategies_quadratic(&record), number_of_winning_strategies(&record), "{record:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let (input, records) = parse_input("Time:      7  15   30\n\
+            Distance:  9  40  200").unwrap();
+        assert_eq!(input, "");
+        assert_eq!(records, vec!(
+            RaceRecord { time: 7, distance: 9 },
+            RaceRecord { time: 15, distance: 40 },
+            RaceRecord { time: 30, distance: 200 },
+        ));
+        assert_eq!(part_one(records), 288);
+    }
+}
@@ -2,12 +2,12 @@ use std::cmp::Ordering;
 use std::cmp::max;
 use std::fmt;
 use std::convert::From;
-use std::convert::TryFrom;
 use enum_ordinalize::Ordinalize;
 use itertools::Itertools;
 
+/// Classification of a hand, strongest first.
 #[derive(Debug, PartialEq, Eq, Ordinalize, Clone)]
-enum HandType {
+pub enum HandType {
     FiveOfAKind,
     FourOfAKind,
     FullHouse,
@@ -17,6 +17,7 @@ enum HandType {
     HighCard
 }
 
+/// Five camel cards, ordered first by type and then card by card.
 #[derive(Clone)]
 pub struct Hand {
     // Assume all contents are sorted upon creation.
@@ -60,6 +61,12 @@ impl From<[u8;5]> for Hand {
     }
 }
 
+impl Hand {
+    pub fn hand_type(&self) -> &HandType {
+        &self.htype
+    }
+}
+
 fn classify_hand(cards: [u8;5]) -> HandType {
   // TODO - what else do we need to remember? 
   let mut max_dupes = 0;
@@ -89,7 +96,7 @@ fn compare_hands_by_card(lhs: &Hand, rhs: &Hand) -> Ordering {
         return Ordering::Less;
       }
     }
-    return Ordering::Equal;
+    Ordering::Equal
 }
 
 impl PartialEq for Hand {
@@ -118,6 +125,7 @@ impl Ord for Hand {
 
 
 #[cfg(test)]
+#[allow(clippy::useless_vec)]
 mod tests {
     use crate::hand::*;
 
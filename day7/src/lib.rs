@@ -0,0 +1,119 @@
+//! Day 7: Camel Cards
+//!
+//! Ranks hands of camel cards, and totals up the winnings of each bid.
+pub mod hand;
+
+use hand::Hand;
+use nom::IResult;
+use nom::bytes::complete::tag;
+use nom::branch::alt;
+use nom::combinator::value;
+use nom::multi::count;
+use nom::character::complete::{
+    space1, 
+    u32,
+};
+
+/// A player's hand, and how much they bid on it.
+#[derive(Clone)]
+pub struct Person {
+    pub hand: Hand,
+    pub bid: u32,
+}
+
+/// Part one: the total winnings, where each bid is multiplied by its hand's rank.
+pub fn part_one_solution(people: Vec<Person>) -> u32 {
+    let mut sorted: Vec<Person> = people.clone();
+    sorted.sort_by(|lhs,rhs| lhs.hand.cmp(&rhs.hand));
+    sorted.iter()
+    .enumerate()
+    .map(|(count, p)| {
+        let rank: u32 = (count+1).try_into().unwrap();
+        explain::event!("rank",
+          format!("{:?} ({:?}) ranks {rank}, winning {}", p.hand, p.hand.hand_type(), p.bid*rank),
+          hand = p.hand, hand_type = p.hand.hand_type(), rank, bid = p.bid);
+        p.bid*rank
+    }).sum()
+}
+
+/// Parses a single card into its value: 2-9, then T=10, J=11, Q=12, K=13, A=14.
+pub fn parse_card(input: &str) -> IResult<&str,u8> {
+    alt((
+        value(1, tag("1")),
+        value(2, tag("2")),
+        value(3, tag("3")),
+        value(4, tag("4")),
+        value(5, tag("5")),
+        value(6, tag("6")),
+        value(7, tag("7")),
+        value(8, tag("8")),
+        value(9, tag("9")),
+        value(10, tag("T")),
+        value(11, tag("J")),
+        value(12, tag("Q")),
+        value(13, tag("K")),
+        value(14, tag("A")),
+    ))(input)
+}
+
+/// Parses the five cards of a hand.
+pub fn parse_hand(input: &str) -> IResult<&str, Hand> {
+    let (input, vec) = count(parse_card, 5)(input)?;
+    let ar5: [u8;5] = vec.as_slice().try_into().unwrap();
+    Ok((input, Hand::from(ar5)))
+}
+
+/// Parses a hand followed by its bid, e.g. `32T3K 765`.
+pub fn parse_bid(input: &str) -> IResult<&str, Person> {
+    let (input, hand) = parse_hand(input)?;
+    let (input, _) = space1(input)?;
+    let (input, bid) = u32(input)?;
+    Ok((input, Person {
+        hand,
+        bid,
+    }))
+}
+
+/// Parses every bid in the input, one per line.
+pub fn parse_input(input: &str) -> Vec<Person> {
+    input.lines()
+      .map(|line| {
+        let (_, result) = parse_bid(line).unwrap();
+        result
+      })
+      .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::hand::*;
+
+    #[test]
+    fn test_parse_hand() {
+        let (input, hand) = parse_hand("KKQ13").unwrap();
+        assert_eq!(input, "");
+        assert_eq!(hand, Hand::from([13,13,12,1,3]));
+    }
+
+    #[test]
+    fn test_hand_types() {
+        let hands: String = parse_input(include_str!("../example.txt")).iter()
+          .map(|p| format!("{:?} {:?}\n", p.hand, p.hand.hand_type()))
+          .collect();
+        snapshot::assert_snapshot!("hand_types", hands);
+    }
+
+    #[test]
+    fn test_explain_rank_order() {
+        let people = parse_input("32T3K 765\n\
+            T55J5 684\n\
+            KK677 28\n\
+            KTJJT 220\n\
+            QQQJA 483");
+        let (result, events) = explain::capture(|| part_one_solution(people));
+        assert_eq!(result, 6440);
+        let order: Vec<&str> = events.iter().map(|e| e.fields[0].1.as_str()).collect();
+        assert_eq!(order, vec!("32T3K", "KTJJT", "KK677", "T55J5", "QQQJA"));
+    }
+}
\ No newline at end of file
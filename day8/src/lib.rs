@@ -0,0 +1,151 @@
+
+//! Day 8: Haunted Wasteland
+//!
+//! Follows left/right instructions through a network of nodes.
+use nom::IResult;
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::combinator::value;
+use nom::multi::*;
+use nom::character::complete::*;
+use std::collections::HashMap;
+
+/// One left/right instruction.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// A node of the network, and the nodes its left and right lead to.
+#[derive(Debug, PartialEq)]
+pub struct Node {
+    pub name: String,
+    pub left: String,
+    pub right: String,
+}
+
+/// Parses a single `L` or `R`.
+pub fn parse_direction(input: &str) -> IResult<&str, Direction> {
+    alt((
+        value(Direction::Left, tag("L")),
+        value(Direction::Right, tag("R"))
+    ))(input)
+}
+
+/// Parses the instruction line.
+pub fn parse_directions(input: &str) -> IResult<&str, Vec<Direction>> {
+    many1(parse_direction)(input)
+}
+
+/// Parses a three character node name.
+pub fn parse_id(input: &str) -> IResult<&str, String> {
+    let (input, result) = count(anychar, 3)(input)?;
+    Ok((input, String::from_iter(result)))
+}
+
+/// Parses a node line.
+// XYZ = (ABC, DEF)
+pub fn parse_node(input: &str) -> IResult<&str, Node> {
+    let (input, name) = parse_id(input)?;
+    let (input, _) = space1(input)?;
+    let (input, _) = tag("=")(input)?;
+    let (input, _) = space1(input)?;
+    let (input, _) = tag("(")(input)?;
+    let (input, left) = parse_id(input)?;
+    let (input, _) = tag(",")(input)?;
+    let (input, _) = space0(input)?;
+    let (input, right) = parse_id(input)?;
+    let (input, _) = tag(")")(input)?;
+    Ok((input, Node {
+        name,
+        left,
+        right,
+    }))
+}
+
+/// Parses the instructions, a blank line, then one node per line.
+pub fn parse_input(input: &str) -> IResult<&str, (Vec<Direction>, Vec<Node>)> {
+    let (input, directions) = parse_directions(input)?;
+    let (input, _) = line_ending(input)?;
+    // Empty line:
+    let (input, _) = line_ending(input)?;
+    // Now nodes one per line
+    let (input, nodes) = separated_list1(line_ending, parse_node)(input)?;
+    Ok((input, (directions, nodes)))
+}
+
+/// Part one: how many steps it takes to get from `AAA` to `ZZZ`.
+pub fn solve_part_1(directions: Vec<Direction>, nodes: Vec<Node>) -> u32 {
+    let mut node_lookup = HashMap::new();
+    for node in nodes.iter() {
+        node_lookup.insert(node.name.as_str(), node);
+    }
+    let mut direction_iterator = directions.iter().cloned().cycle();
+    let mut current_node = "AAA";
+    let mut count: u32 = 0;
+    // Nothing stops us walking forever if ZZZ can't be reached.
+    watchdog::stage("walk to ZZZ");
+    while current_node != "ZZZ" {
+      count += 1;
+      watchdog::progress(count.into(), None);
+      let Some(direction) = direction_iterator.next() else { panic!("Directions should be infinite iterator")};
+      let Some(node) = node_lookup.get(current_node) else { panic!("Badly formed puzzle, can't find {current_node}")};
+      match direction {
+        Direction::Left => current_node = node.left.as_str(),
+        Direction::Right => current_node = node.right.as_str(),
+      }
+      if (count as usize).is_multiple_of(directions.len()) {
+        explain::event!("pass",
+          format!("after {count} steps, all instructions used, at {current_node}"),
+          steps = count, node = current_node);
+      }
+    }
+    explain::event!("arrived", format!("reached ZZZ after {count} steps"), steps = count);
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_parse_directions() {
+        assert_eq!(parse_directions("LR"), Ok(("", vec!(Direction::Left, Direction::Right))));
+        assert_eq!(parse_directions("RRL"), Ok(("", vec!(Direction::Right, Direction::Right, Direction::Left))));
+    }
+
+    #[test]
+    fn test_parse_id() {
+        let (input, result) = parse_id("ABC").unwrap();
+        assert_eq!(input, "");
+        assert_eq!(result, "ABC");
+    }
+
+    #[test]
+    fn test_parse_node() {
+        let (input, result) = parse_node("AAA = (BBB,CCC)").unwrap();
+        assert_eq!(input, "");
+        assert_eq!(result, Node {
+            name: String::from("AAA"),
+            left: String::from("BBB"),
+            right: String::from("CCC"),
+        });
+    }
+
+    #[test]
+    fn test_input() {
+        let (input, (directions, nodes)) = parse_input("RL\n\
+            \n\
+            AAA = (BBB, CCC)\n\
+            BBB = (DDD, EEE)\n\
+            CCC = (ZZZ, GGG)\n\
+            DDD = (DDD, DDD)\n\
+            EEE = (EEE, EEE)\n\
+            GGG = (GGG, GGG)\n\
+            ZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(input, "");
+        assert_eq!(directions.len(), 2);
+        assert_eq!(nodes.len(), 7);
+    }
+}
\ No newline at end of file
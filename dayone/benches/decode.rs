@@ -0,0 +1,51 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use dayone::automaton::Scanner;
+use dayone::extractor::Words;
+use dayone::Tokenizer;
+
+const DIGIT_WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+// A calibration dump of about `bytes` bytes, of lines like `xtwo1nineight`.
+fn calibration_dump(bytes: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(1);
+    let mut dump = String::with_capacity(bytes + 64);
+    while dump.len() < bytes {
+        for _ in 0..rng.gen_range(1..=8) {
+            match rng.gen_range(0..3) {
+                0 => dump.push(rng.gen_range('a'..='z')),
+                1 => dump.push(char::from_digit(rng.gen_range(1..=9), 10).unwrap()),
+                _ => dump.push_str(DIGIT_WORDS.choose(&mut rng).unwrap()),
+            }
+        }
+        dump.push('\n');
+    }
+    dump
+}
+
+fn decode(c: &mut Criterion) {
+    for megabytes in [1, 4] {
+        let dump = calibration_dump(megabytes << 20);
+        let mut group = c.benchmark_group(format!("decode {megabytes}MB"));
+        group.sample_size(10);
+        group.throughput(Throughput::Bytes(dump.len() as u64));
+        // Both sides are summed the same way, so only reading the lines differs.
+        for tokenizer in [Tokenizer::Consuming, Tokenizer::Overlapping] {
+            let words = Words::english(tokenizer);
+            group.bench_with_input(BenchmarkId::new("nom", format!("{tokenizer:?}")), &dump, |b, dump| {
+                b.iter(|| dayone::decode_msg_with(dump, &words).unwrap())
+            });
+            let scanner = Scanner::new(tokenizer);
+            group.bench_with_input(BenchmarkId::new("automaton", format!("{tokenizer:?}")), &dump, |b, dump| {
+                b.iter(|| dayone::decode_msg_with(dump, &scanner).unwrap())
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, decode);
+criterion_main!(benches);
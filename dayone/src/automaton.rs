@@ -0,0 +1,244 @@
+//! Part two in a single pass: every digit and number word is found by one
+//! multi-pattern automaton, without allocating anything per line.
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, Input, Match, MatchKind};
+use std::cmp::{self, Reverse};
+use std::iter;
+
+use crate::extractor::Extractor;
+use crate::vocabulary::Vocabulary;
+use crate::{calibration_sum, decode_msg_with, roman_at, unicode, Kind, Token, Tokenizer};
+
+const ROMAN_NUMERALS: [&str; 9] = ["i", "ii", "iii", "iv", "v", "vi", "vii", "viii", "ix"];
+
+// Every way of writing `word` whatever its case. The automaton ignores the
+// case of ASCII letters by itself, so only the others need spelling out.
+fn case_variants(word: &str) -> Vec<String> {
+    word.chars().fold(vec!(String::new()), |variants, c| {
+        let mut cases = vec!(c);
+        if !c.is_ascii() {
+            cases.extend(c.to_lowercase().chain(c.to_uppercase()).filter(|other| *other != c));
+            cases.dedup();
+        }
+        variants.iter().flat_map(|variant| cases.iter().map(move |c| format!("{variant}{c}"))).collect()
+    })
+}
+
+/// Finds the digits and number words of lines with an Aho-Corasick
+/// automaton, giving the same tokens as [`crate::tokens_with`] does with the
+/// same tokenizer and vocabulary. A line's first and last digit are found
+/// without allocating anything.
+pub struct Scanner {
+    tokenizer: Tokenizer,
+    automaton: AhoCorasick,
+    // The value and kind of each pattern, by its index. Roman numerals only
+    // count as a whole word.
+    values: Vec<(u32, Kind)>,
+}
+
+impl Scanner {
+    /// A scanner for the digits and the english words one-nine.
+    pub fn new(tokenizer: Tokenizer) -> Scanner {
+        Scanner::with_vocabulary(tokenizer, &Vocabulary::english())
+    }
+
+    /// A scanner for the digits and number words of `vocabulary`.
+    pub fn with_vocabulary(tokenizer: Tokenizer, vocabulary: &Vocabulary) -> Scanner {
+        // Overlapping searches need the standard match semantics. Consuming
+        // ones take the longest word at the leftmost place one starts, just
+        // like the nom tokenizer does.
+        let kind = match tokenizer {
+            Tokenizer::Consuming => MatchKind::LeftmostLongest,
+            Tokenizer::Overlapping => MatchKind::Standard,
+        };
+        // Every script's digits that count, then the words, then any Roman
+        // numerals.
+        let digits = unicode::decimal_digits().filter(|(_, value)| *value > 0 || vocabulary.has_zero()).map(|(c, value)| (c.to_string(), (value, Kind::Digit)));
+        let words = vocabulary.words().flat_map(|(word, value)| case_variants(word).into_iter().map(move |word| (word, (value, Kind::Word))));
+        let romans = ROMAN_NUMERALS.iter().zip(1..).filter(|_| vocabulary.has_roman()).map(|(numeral, value)| (numeral.to_string(), (value, Kind::Roman)));
+        let (patterns, values): (Vec<String>, Vec<(u32, Kind)>) = digits.chain(words).chain(romans).unzip();
+        let automaton = AhoCorasickBuilder::new()
+          .ascii_case_insensitive(true)
+          .match_kind(kind)
+          .build(patterns)
+          .unwrap();
+        Scanner { tokenizer, automaton, values }
+    }
+
+    fn value(&self, found: &Match) -> u32 {
+        self.values[found.pattern().as_usize()].0
+    }
+
+    fn token(&self, found: &Match) -> Token {
+        let (value, kind) = self.values[found.pattern().as_usize()];
+        Token { span: found.range(), kind, value }
+    }
+
+    // Whether a match counts: a Roman numeral has to be a whole word.
+    fn counts(&self, line: &str, found: &Match) -> bool {
+        self.values[found.pattern().as_usize()].1 != Kind::Roman || roman_at(line, found.start()).is_some_and(|(_, len)| len == found.len())
+    }
+
+    // The tokens of a line, in order, when each one is used up once found.
+    fn consuming<'a>(&'a self, line: &'a str) -> impl Iterator<Item = Match> + 'a {
+        let mut at = 0;
+        iter::from_fn(move || {
+            while let Some(found) = self.automaton.find(Input::new(line).span(at..line.len())) {
+                if self.counts(line, &found) {
+                    at = found.end();
+                    return Some(found);
+                }
+                // Something else may start inside a numeral that doesn't count.
+                at = found.start() + line[found.start()..].chars().next().map_or(1, char::len_utf8);
+            }
+            None
+        })
+    }
+
+    // Every match that counts, when tokens may overlap, in the order they end.
+    fn overlapping<'a>(&'a self, line: &'a str) -> impl Iterator<Item = Match> + 'a {
+        self.automaton.find_overlapping_iter(line).filter(|found| self.counts(line, found))
+    }
+
+    // The first and last token of a line, as the nom tokenizer splits it.
+    fn first_and_last(&self, line: &str) -> Option<(Match, Match)> {
+        match self.tokenizer {
+            Tokenizer::Consuming => {
+                let mut found = self.consuming(line);
+                let first = found.next()?;
+                Some((first, found.last().unwrap_or(first)))
+            },
+            Tokenizer::Overlapping => {
+                // A token starts at every character, the longest word there
+                // winning, but matches come in the order they end.
+                let mut found = self.overlapping(line);
+                let start = found.next()?;
+                Some(found.fold((start, start), |(first, last), found| (
+                    cmp::min_by_key(first, found, |found| (found.start(), Reverse(found.end()))),
+                    cmp::max_by_key(last, found, |found| (found.start(), found.end())),
+                )))
+            },
+        }
+    }
+}
+
+impl Extractor for Scanner {
+    fn tokens(&self, line: &str) -> Vec<Token> {
+        match self.tokenizer {
+            Tokenizer::Consuming => self.consuming(line).map(|found| self.token(&found)).collect(),
+            Tokenizer::Overlapping => {
+                let mut found: Vec<Match> = self.overlapping(line).collect();
+                found.sort_by_key(|found| (found.start(), Reverse(found.end())));
+                found.dedup_by_key(|found| found.start());
+                found.iter().map(|found| self.token(found)).collect()
+            },
+        }
+    }
+
+    // Straight from the first and last match, without allocating. Other
+    // rules, through [`Extractor::with_rule`], go through every token.
+    fn decode_line(&self, line: &str) -> Option<u64> {
+        match self.first_and_last(line) {
+            Some((first, last)) => self.combine(&[self.value(&first), self.value(&last)]),
+            None => self.combine(&[]),
+        }
+    }
+}
+
+/// Part two, scanned by one automaton, as [`crate::decode_msg`] reads it.
+pub fn decode_msg_automaton(msg: &str) -> u32 {
+    calibration_sum(decode_msg_with(msg, &Scanner::new(Tokenizer::Consuming)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::automaton::*;
+    use crate::combination::Rule;
+    use crate::{decode_line2, decode_line2_with, decode_line_overlapping, tokens_with};
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_scanner() {
+        let consuming = Scanner::new(Tokenizer::Consuming);
+        let overlapping = Scanner::new(Tokenizer::Overlapping);
+        assert_eq!(consuming.decode_line("oneight"), Some(11));
+        assert_eq!(overlapping.decode_line("oneight"), Some(18));
+        assert_eq!(overlapping.decode_line("xTWOne"), Some(21));
+        assert_eq!(overlapping.decode_line("zero0"), Some(0));
+        assert_eq!(overlapping.decode_line(""), Some(0));
+        assert_eq!(decode_msg_automaton("two1nine\neightwothree\nabcone2threexyz\n"), 29 + 83 + 13);
+        assert_eq!(decode_msg_automaton("oneight\n"), crate::decode_msg("oneight\n"));
+    }
+
+    #[test]
+    fn test_rules() {
+        let overlapping = Scanner::new(Tokenizer::Overlapping);
+        assert_eq!(overlapping.tokens("xtwone3"), vec!(
+            Token { span: 1..4, kind: Kind::Word, value: 2 },
+            Token { span: 3..6, kind: Kind::Word, value: 1 },
+            Token { span: 6..7, kind: Kind::Digit, value: 3 },
+        ));
+        let concatenate = Scanner::new(Tokenizer::Overlapping).with_rule(Rule::Concatenate);
+        assert_eq!(concatenate.decode_line("xtwone3"), Some(213));
+        assert_eq!(concatenate.decode_line(&"9".repeat(20)), None);
+        assert_eq!(decode_msg_with("1twone3\n", &Scanner::new(Tokenizer::Consuming).with_rule(Rule::Sum)), Ok(6));
+    }
+
+    #[test]
+    fn test_case_variants() {
+        assert_eq!(case_variants("one"), vec!("one"));
+        assert_eq!(case_variants("zéro"), vec!("zéro", "zÉro"));
+        assert_eq!(case_variants("fünf").len(), 2);
+    }
+
+    #[test]
+    fn test_vocabulary() {
+        let vocabulary = Vocabulary::for_locales("fr,de").unwrap().with_zero().with_roman();
+        let consuming = Scanner::with_vocabulary(Tokenizer::Consuming, &vocabulary);
+        assert_eq!(consuming.decode_line("aDEUXbtrois"), Some(23));
+        assert_eq!(consuming.decode_line("ZÉRO fÜnf"), Some(5));
+        assert_eq!(consuming.decode_line("vivid iv"), Some(44));
+        // A numeral that isn't a whole word doesn't hide a word inside it.
+        assert_eq!(consuming.decode_line("ivier"), Some(44));
+        // "une" is one word, not "un" then an "e".
+        assert_eq!(Scanner::with_vocabulary(Tokenizer::Overlapping, &vocabulary).decode_line("une"), Some(11));
+    }
+
+    #[test]
+    fn test_same_as_nom() {
+        let consuming = Scanner::new(Tokenizer::Consuming);
+        let overlapping = Scanner::new(Tokenizer::Overlapping);
+        for line in ["two1nine", "eightwothree", "4nineeightseven2", "zoneight234", "7pqrstsixteen", "sevenine", "twone", "x", "ONEtWo", "٣x５", "½one²"] {
+            assert_eq!(consuming.decode_line(line), Some(u64::from(decode_line2(line))), "{line}");
+            assert_eq!(overlapping.decode_line(line), Some(u64::from(decode_line_overlapping(line))), "{line}");
+        }
+    }
+
+    // A line of fragments likely to trip up a scanner: words running into
+    // each other, mixed case, accents, zeroes and Roman numerals.
+    fn random_line(rng: &mut ChaCha8Rng) -> String {
+        const FRAGMENTS: [&str; 22] = ["one", "EIGHT", "tw", "o", "ne", "0", "7", "x", "zéro", "ZÉRO", "deux", "fÜnf", "uno", "une", "sieben", "é", "iv", "I", " ", "vi", "vier", "cho"];
+        (0..rng.gen_range(0..10)).map(|_| FRAGMENTS[rng.gen_range(0..FRAGMENTS.len())]).collect()
+    }
+
+    #[test]
+    fn test_same_as_nom_with_vocabulary() {
+        let vocabularies = [
+            Vocabulary::empty(),
+            Vocabulary::english().with_zero(),
+            Vocabulary::for_locales("en,fr,de,es").unwrap().with_zero(),
+            Vocabulary::for_locales("en,de").unwrap().with_roman(),
+        ];
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        for vocabulary in &vocabularies {
+            for tokenizer in [Tokenizer::Consuming, Tokenizer::Overlapping] {
+                let scanner = Scanner::with_vocabulary(tokenizer, vocabulary);
+                for _ in 0..500 {
+                    let line = random_line(&mut rng);
+                    assert_eq!(scanner.decode_line(&line), Some(u64::from(decode_line2_with(&line, tokenizer, vocabulary))), "{line} {tokenizer:?}");
+                    assert_eq!(scanner.tokens(&line), tokens_with(&line, tokenizer, vocabulary), "{line} {tokenizer:?}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Decoding a line without reading all of it: forward from the start to the
+//! first digit, then backward from the end to the last.
+//!
+//! This implements the overlapping tokenizer, [`Tokenizer::Overlapping`],
+//! and nothing else: it gives the values of the overlapping
+//! [`crate::extractor::Words`] extractor with the same vocabulary, and so of
+//! [`crate::decode_msg_overlapping`], the part two registered alongside it.
+//! Reading backward can't give those of [`Tokenizer::Consuming`], where
+//! whether "eightwo" ends in "two" depends on the "eight" before it.
+use crate::combination::Rule;
+use crate::extractor::Extractor;
+use crate::vocabulary::Vocabulary;
+use crate::{calibration_sum, decode_msg_with, tokens_with, Token, Tokenizer};
+
+// The digit or number word starting at `at`, as the overlapping tokenizer
+// reads it: a Roman numeral, else the longest word, else a digit.
+fn token_at(line: &str, at: usize, vocabulary: &Vocabulary) -> Option<u32> {
+    let rest = &line[at..];
+    vocabulary.roman_at(line, at).map(|(value, _)| value)
+      .or_else(|| vocabulary.word_at(rest).map(|(value, _)| value))
+      .or_else(|| rest.chars().next().and_then(|c| vocabulary.digit(c)))
+}
+
+// Where the Roman numeral ending at `end` starts, if a whole word ends there
+// and it's a numeral.
+fn roman_ending(line: &str, end: usize, vocabulary: &Vocabulary) -> Option<usize> {
+    if line[end..].chars().next().is_some_and(char::is_alphabetic) {
+        return None;
+    }
+    let word = line[..end].char_indices().rev().take_while(|(_, c)| c.is_alphabetic()).last()?;
+    vocabulary.roman_at(line, word.0).filter(|(_, len)| word.0 + len == end).map(|_| word.0)
+}
+
+/// The first digit of a line, stopping as soon as it's found.
+pub fn first_digit(line: &str, vocabulary: &Vocabulary) -> Option<u32> {
+    line.char_indices().find_map(|(at, _)| token_at(line, at, vocabulary))
+}
+
+/// The last digit of a line, from its end, stopping as soon as it's found.
+pub fn last_digit(line: &str, vocabulary: &Vocabulary) -> Option<u32> {
+    // Where the latest token so far starts. Tokens ending further back can
+    // only start later when one word sits inside another, so keep looking
+    // until we're back to the start of the latest one.
+    let mut latest: Option<usize> = None;
+    for (at, c) in line.char_indices().rev() {
+        let end = at + c.len_utf8();
+        if latest.is_some_and(|start| end <= start) {
+            break;
+        }
+        let before = &line[..end];
+        let digit = vocabulary.digit(c).map(|_| at);
+        let roman = roman_ending(line, end, vocabulary);
+        let start = vocabulary.words_ending(before).chain(digit).chain(roman).max();
+        latest = latest.max(start);
+    }
+    token_at(line, latest?, vocabulary)
+}
+
+/// Decodes a line from its first and last digit, or 0 without any, reading
+/// number words as overlapping.
+pub fn decode_line_bidirectional(line: &str, vocabulary: &Vocabulary) -> u64 {
+    let value = match (first_digit(line, vocabulary), last_digit(line, vocabulary)) {
+        (Some(first), Some(last)) => Rule::FirstAndLast.combine(&[first, last]),
+        _ => Rule::FirstAndLast.combine(&[]),
+    };
+    // Two digits always fit.
+    value.unwrap()
+}
+
+/// The overlapping tokens of a vocabulary, like [`crate::extractor::Words`],
+/// but taking a line's first and last digit from its two ends. Other rules,
+/// through [`Extractor::with_rule`], need every token, and read all of it.
+#[derive(Clone, Debug)]
+pub struct Bidirectional {
+    pub vocabulary: Vocabulary,
+}
+
+impl Extractor for Bidirectional {
+    fn tokens(&self, line: &str) -> Vec<Token> {
+        tokens_with(line, Tokenizer::Overlapping, &self.vocabulary)
+    }
+
+    fn decode_line(&self, line: &str) -> Option<u64> {
+        Some(decode_line_bidirectional(line, &self.vocabulary))
+    }
+}
+
+/// Part one, scanning each line from both ends.
+pub fn part_one(msg: &str) -> u32 {
+    calibration_sum(decode_msg_with(msg, &Bidirectional { vocabulary: Vocabulary::empty().with_zero() }))
+}
+
+/// Part two, with overlapping number words, scanning each line from both
+/// ends: the same as [`crate::decode_msg_overlapping`].
+pub fn decode_msg_bidirectional(msg: &str) -> u32 {
+    calibration_sum(decode_msg_with(msg, &Bidirectional { vocabulary: Vocabulary::english() }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bidirectional::*;
+    use crate::extractor::{Digits, Extractor, Words};
+    use crate::decode_msg_overlapping;
+    use crate::tokenize_with;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_first_and_last() {
+        let english = Vocabulary::english();
+        assert_eq!(first_digit("xtwone3", &english), Some(2));
+        assert_eq!(last_digit("3xtwone", &english), Some(1));
+        assert_eq!(last_digit("eighTWO", &english), Some(2));
+        assert_eq!(last_digit("abc", &english), None);
+        assert_eq!(decode_line_bidirectional("7pqrstsixteen", &english), 76);
+        assert_eq!(decode_line_bidirectional("", &english), 0);
+        assert_eq!(part_one("1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet"), 142);
+        assert_eq!(decode_msg_bidirectional("two1nine\neightwothree\nzoneight234\n"), 29 + 83 + 14);
+        // Overlapping, not consuming, number words.
+        assert_eq!(decode_line_bidirectional("eightwo", &english), 82);
+        assert_eq!(decode_msg_bidirectional("oneight\neightwo"), decode_msg_overlapping("oneight\neightwo"));
+    }
+
+    #[test]
+    fn test_rules() {
+        let bidirectional = Bidirectional { vocabulary: Vocabulary::english() };
+        assert_eq!(bidirectional.decode_line("xtwone3"), Some(23));
+        assert_eq!(bidirectional.clone().with_rule(Rule::Concatenate).decode_line("xtwone3"), Some(213));
+        assert_eq!(bidirectional.with_rule(Rule::Sum).decode_line("xtwone3"), Some(6));
+    }
+
+    #[test]
+    fn test_roman() {
+        let roman = Vocabulary::english().with_roman();
+        assert_eq!(last_digit("ii and iv", &roman), Some(4));
+        assert_eq!(last_digit("iv vivid", &roman), Some(4));
+        assert_eq!(last_digit("ix3", &roman), Some(3));
+        assert_eq!(first_digit("vivid viii", &roman), Some(8));
+        assert_eq!(decode_line_bidirectional("part VI of ix", &roman), 69);
+    }
+
+    #[test]
+    fn test_nested_words() {
+        // "seven" ends after "eve", but the last token is the one starting last.
+        let vocabulary = Vocabulary::parse("7 seven\n5 eve").unwrap();
+        assert_eq!(tokenize_with("xsevenx", Tokenizer::Overlapping, &vocabulary), vec!(7, 5));
+        assert_eq!(last_digit("xsevenx", &vocabulary), Some(5));
+    }
+
+    // A line of fragments likely to trip up a scanner: words running into
+    // each other, mixed case, accents and zeroes.
+    fn random_line(rng: &mut ChaCha8Rng) -> String {
+        const FRAGMENTS: [&str; 20] = ["one", "EIGHT", "tw", "o", "ne", "0", "7", "x", "zéro", "ZERO", "deux", "fÜnf", "uno", "une", "sieben", "é", "iv", "I", " ", "vi"];
+        (0..rng.gen_range(0..10)).map(|_| FRAGMENTS[rng.gen_range(0..FRAGMENTS.len())]).collect()
+    }
+
+    #[test]
+    fn test_same_as_tokenizer() {
+        let vocabularies = [
+            Vocabulary::empty(),
+            Vocabulary::english(),
+            Vocabulary::english().with_zero(),
+            Vocabulary::for_locales("en,fr,de,es").unwrap().with_zero(),
+            Vocabulary::english().with_roman(),
+        ];
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        for _ in 0..2000 {
+            let line = random_line(&mut rng);
+            for vocabulary in &vocabularies {
+                let tokens = tokenize_with(&line, Tokenizer::Overlapping, vocabulary);
+                assert_eq!(first_digit(&line, vocabulary), tokens.first().copied(), "{line}");
+                assert_eq!(last_digit(&line, vocabulary), tokens.last().copied(), "{line}");
+                let words = Words { vocabulary: vocabulary.clone(), tokenizer: Tokenizer::Overlapping };
+                assert_eq!(Some(decode_line_bidirectional(&line, vocabulary)), words.decode_line(&line), "{line}");
+            }
+            assert_eq!(Some(decode_line_bidirectional(&line, &Vocabulary::empty().with_zero())), Digits.decode_line(&line), "{line}");
+        }
+    }
+}
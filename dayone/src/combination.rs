@@ -0,0 +1,85 @@
+//! Ways of making a line's value from its digits. The puzzle takes the
+//! first and last, but other documents want more of them, or all of them.
+use std::fmt;
+
+/// How a line's digits, in order, make its value. Lines without digits are
+/// worth 0 whatever the rule.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rule {
+    /// The first digit then the last, e.g. 15 for 1, 2, 3, 4, 5.
+    FirstAndLast,
+    /// The first `k` digits then the last `k`, e.g. 1245 for 1, 2, 3, 4, 5
+    /// with `k` = 2. Lines with fewer than `2k` digits repeat some, just as
+    /// a lone 7 makes 77.
+    Ends(usize),
+    /// Every digit, e.g. 12345.
+    Concatenate,
+    /// The sum of the digits, e.g. 15.
+    Sum,
+}
+
+// The digits as one decimal number, or None if it doesn't fit.
+fn concatenate<'a>(digits: impl IntoIterator<Item = &'a u32>) -> Option<u64> {
+    digits.into_iter().try_fold(0u64, |value, digit| value.checked_mul(10)?.checked_add(u64::from(*digit)))
+}
+
+impl Rule {
+    /// The value of a line with these digits, or None if it doesn't fit in
+    /// a u64.
+    pub fn combine(&self, digits: &[u32]) -> Option<u64> {
+        if digits.is_empty() {
+            return Some(0);
+        }
+        match self {
+            Rule::FirstAndLast => Rule::Ends(1).combine(digits),
+            Rule::Ends(k) => {
+                let k = (*k).min(digits.len());
+                concatenate(digits[..k].iter().chain(&digits[digits.len() - k..]))
+            },
+            Rule::Concatenate => concatenate(digits),
+            Rule::Sum => digits.iter().try_fold(0u64, |sum, digit| sum.checked_add(u64::from(*digit))),
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rule::FirstAndLast => write!(f, "first and last digit"),
+            Rule::Ends(k) => write!(f, "first and last {k} digits"),
+            Rule::Concatenate => write!(f, "every digit"),
+            Rule::Sum => write!(f, "sum of the digits"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::combination::*;
+
+    const DIGITS: [u32; 5] = [1, 2, 3, 4, 5];
+
+    #[test]
+    fn test_rules() {
+        assert_eq!(Rule::FirstAndLast.combine(&DIGITS), Some(15));
+        assert_eq!(Rule::FirstAndLast.combine(&[7]), Some(77));
+        assert_eq!(Rule::Ends(2).combine(&DIGITS), Some(1245));
+        assert_eq!(Rule::Ends(1).combine(&DIGITS), Some(15));
+        assert_eq!(Rule::Ends(4).combine(&[1, 2, 3]), Some(123123));
+        assert_eq!(Rule::Concatenate.combine(&DIGITS), Some(12345));
+        assert_eq!(Rule::Sum.combine(&DIGITS), Some(15));
+        for rule in [Rule::FirstAndLast, Rule::Ends(3), Rule::Concatenate, Rule::Sum] {
+            assert_eq!(rule.combine(&[]), Some(0), "{rule}");
+        }
+    }
+
+    #[test]
+    fn test_overflow() {
+        // u64 holds any 19 digits, but not every 20.
+        assert_eq!(Rule::Concatenate.combine(&[9; 19]), Some(9_999_999_999_999_999_999));
+        assert_eq!(Rule::Concatenate.combine(&[1; 20]), Some(11_111_111_111_111_111_111));
+        assert_eq!(Rule::Concatenate.combine(&[9; 20]), None);
+        assert_eq!(Rule::Ends(10).combine(&[9; 30]), None);
+        assert_eq!(Rule::Sum.combine(&[9; 30]), Some(270));
+    }
+}
@@ -0,0 +1,146 @@
+//! How a line of a calibration document turns into its value, in two
+//! pluggable steps: where its digits come from, and how they're combined.
+use crate::vocabulary::Vocabulary;
+use crate::combination::Rule;
+use crate::{phrase, tokens_with, Token, Tokenizer};
+
+/// Reads the digits of a line and combines them into its value.
+///
+/// Both parts of the puzzle are extractors, [`Digits`] and [`Words`], as is
+/// reading whole phrases, [`Phrases`], and [`crate::decode_msg_with`] sums a
+/// document with any of them.
+pub trait Extractor {
+    /// Every digit or number in a line, in order, with where it is.
+    fn tokens(&self, line: &str) -> Vec<Token>;
+
+    /// A line's value from its digits, in order, or None if it doesn't fit.
+    /// The first and last digit unless an extractor says otherwise.
+    fn combine(&self, digits: &[u32]) -> Option<u64> {
+        Rule::FirstAndLast.combine(digits)
+    }
+
+    /// The digits of every token in a line, in order.
+    fn digits(&self, line: &str) -> Vec<u32> {
+        self.tokens(line).into_iter().flat_map(|token| phrase::digits(token.value)).collect()
+    }
+
+    /// A line's value, or None if it doesn't fit.
+    fn decode_line(&self, line: &str) -> Option<u64> {
+        self.combine(&self.digits(line))
+    }
+
+    /// The same tokens, combined by `rule` instead.
+    fn with_rule(self, rule: Rule) -> Combined<Self> where Self: Sized {
+        Combined { extractor: self, rule }
+    }
+}
+
+/// Another extractor's tokens, combined by a [`Rule`] of our choosing.
+#[derive(Clone, Debug)]
+pub struct Combined<E> {
+    pub extractor: E,
+    pub rule: Rule,
+}
+
+impl<E: Extractor> Extractor for Combined<E> {
+    fn tokens(&self, line: &str) -> Vec<Token> {
+        self.extractor.tokens(line)
+    }
+
+    fn combine(&self, digits: &[u32]) -> Option<u64> {
+        self.rule.combine(digits)
+    }
+}
+
+/// Part one: only the digits count, 0 included.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Digits;
+
+impl Extractor for Digits {
+    fn tokens(&self, line: &str) -> Vec<Token> {
+        // Without any words, the tokenizer only finds the digits.
+        tokens_with(line, Tokenizer::Consuming, &Vocabulary::empty().with_zero())
+    }
+}
+
+/// Part two reading compound number phrases as their full value, so
+/// "twenty-one" gives the digits 2 and 1, see [`crate::phrase`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Phrases;
+
+impl Extractor for Phrases {
+    fn tokens(&self, line: &str) -> Vec<Token> {
+        phrase::phrase_tokens(line)
+    }
+}
+
+/// Part two: digits and the number words of a vocabulary, english or not.
+#[derive(Clone, Debug)]
+pub struct Words {
+    pub vocabulary: Vocabulary,
+    pub tokenizer: Tokenizer,
+}
+
+impl Words {
+    /// Digits and the english words one-nine.
+    pub fn english(tokenizer: Tokenizer) -> Words {
+        Words { vocabulary: Vocabulary::english(), tokenizer }
+    }
+}
+
+impl Extractor for Words {
+    fn tokens(&self, line: &str) -> Vec<Token> {
+        tokens_with(line, self.tokenizer, &self.vocabulary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::extractor::*;
+    use crate::Kind;
+
+    // Reads numbers as written, 10 as 1 then 0, and adds up its digits.
+    struct DigitSum;
+
+    impl Extractor for DigitSum {
+        fn tokens(&self, line: &str) -> Vec<Token> {
+            line.char_indices().filter_map(|(at, c)| {
+                c.to_digit(10).map(|value| Token { span: at..at + 1, kind: Kind::Digit, value })
+            }).collect()
+        }
+
+        fn combine(&self, digits: &[u32]) -> Option<u64> {
+            Some(digits.iter().map(|digit| u64::from(*digit)).sum())
+        }
+    }
+
+    #[test]
+    fn test_parts() {
+        assert_eq!(Digits.decode_line("two1nine"), Some(11));
+        assert_eq!(Digits.decode_line("a0b"), Some(0));
+        assert_eq!(Digits.decode_line("a0b5"), Some(5));
+        assert_eq!(Words::english(Tokenizer::Consuming).decode_line("two1nine"), Some(29));
+        assert_eq!(Words::english(Tokenizer::Consuming).decode_line("oneight"), Some(11));
+        assert_eq!(Words::english(Tokenizer::Overlapping).decode_line("oneight"), Some(18));
+        let german = Words { vocabulary: Vocabulary::for_locale("de").unwrap(), tokenizer: Tokenizer::Consuming };
+        assert_eq!(german.decode_line("einsxzwei"), Some(12));
+        assert_eq!(Phrases.decode_line("x3yone hundred"), Some(30));
+        assert_eq!(Phrases.digits("twenty-one"), vec!(2, 1));
+    }
+
+    #[test]
+    fn test_custom() {
+        assert_eq!(DigitSum.digits("a10b9"), vec!(1, 0, 9));
+        assert_eq!(DigitSum.decode_line("a10b9"), Some(10));
+        assert_eq!(DigitSum.decode_line(""), Some(0));
+    }
+
+    #[test]
+    fn test_with_rule() {
+        let words = Words::english(Tokenizer::Overlapping);
+        assert_eq!(words.clone().with_rule(Rule::Concatenate).decode_line("1twone3"), Some(1213));
+        assert_eq!(words.clone().with_rule(Rule::Ends(2)).decode_line("1twone3"), Some(1213));
+        assert_eq!(words.clone().with_rule(Rule::Sum).decode_line("1twone3"), Some(7));
+        assert_eq!(Digits.with_rule(Rule::Concatenate).decode_line(&"9".repeat(20)), None);
+    }
+}
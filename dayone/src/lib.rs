@@ -0,0 +1,592 @@
+//! Day 1: Trebuchet?!
+//!
+//! Decodes calibration documents, where each line's value is made from
+//! its first and last digit.
+pub mod automaton;
+pub mod bidirectional;
+pub mod combination;
+pub mod extractor;
+pub mod phrase;
+pub mod report;
+pub mod stream;
+pub mod strict;
+pub mod unicode;
+pub mod vocabulary;
+
+use nom::IResult;
+use nom::branch::alt;
+use nom::bytes::complete::tag_no_case;
+use nom::character::complete::{anychar, satisfy};
+use nom::error::context;
+use nom::combinator::{consumed, map, not, opt, peek, value};
+use nom::sequence::terminated;
+use nom::error::{Error, ErrorKind};
+use nom::multi::many1;
+use std::ops::Range;
+use extractor::{Digits, Extractor, Phrases, Words};
+use vocabulary::Vocabulary;
+
+// Part 1 - Solution using simple iteration
+/// Decodes a line using only its digits, e.g. `a1b2c3` is 13. Digits from
+/// any script count, so `٣x５` is 35, but other numeric characters like
+/// '½' or '²' don't.
+///
+/// Lines without any digits decode to 0. The digit 0 counts like any other.
+pub fn decode_line(line: &str) -> u32 {
+    decode_line_with(line, &Vocabulary::empty().with_zero())
+}
+
+/// Decodes a line like [`decode_line`], with the digits of `vocabulary`.
+pub fn decode_line_with(line: &str, vocabulary: &Vocabulary) -> u32 {
+    let result: Vec<u32> =
+      line.chars()
+      .filter_map(|x| vocabulary.digit(x))
+      .collect();
+    let value = first_and_last(&result);
+    explain::event!("decode", format!("{line} results in {value}"), line, digits = result, value);
+    explain_ignored(line);
+    value
+}
+
+// Explains which numeric characters of a line weren't read as digits.
+fn explain_ignored(line: &str) {
+    if !explain::enabled() {
+        return;
+    }
+    let ignored = unicode::other_numerics(line);
+    if !ignored.is_empty() {
+        explain::event!("ignore", format!("{line} has numbers that aren't digits"), line, ignored);
+    }
+}
+
+/// Part one: sums the value of every line, using only numeric characters.
+pub fn part_one(msg: &str) -> u32 {
+    calibration_sum(decode_msg_with(msg, &Digits))
+}
+
+/// Sums the value of every line, as read by `extractor`. Fails on the first
+/// line whose value, or the sum so far, doesn't fit in a u64.
+pub fn decode_msg_with(msg: &str, extractor: &impl Extractor) -> Result<u64, String> {
+    decode_lines(msg, 0, 0, extractor)
+}
+
+// Adds the value of every line of `msg` to `sum`, counting its lines from
+// `first`, so that part of a document fails just as the whole would.
+fn decode_lines(msg: &str, first: usize, sum: u64, extractor: &impl Extractor) -> Result<u64, String> {
+    msg.lines().enumerate().try_fold(sum, |sum, (idx, line)| {
+        let number = first + idx + 1;
+        // Only split out the digits when explaining them, so an extractor
+        // can take whatever shortcut it has to a line's value.
+        let digits = explain::enabled().then(|| extractor.digits(line));
+        let value = match &digits {
+            Some(digits) => extractor.combine(digits),
+            None => extractor.decode_line(line),
+        };
+        let value = value.ok_or_else(|| format!("line {number}: the value of {line} is too big"))?;
+        explain::event!("decode", format!("{line} results in {value}"), line, digits = digits.unwrap_or_default(), value);
+        explain_ignored(line);
+        sum.checked_add(value).ok_or_else(|| format!("line {number}: the sum is too big"))
+    })
+}
+
+// The sum of a document, for the parts of the puzzle, whose first and last
+// digits can't add up to more than a u32 on any real input.
+fn calibration_sum(sum: Result<u64, String>) -> u32 {
+    sum.and_then(|sum| u32::try_from(sum).map_err(|_| format!("the sum {sum} is too big"))).unwrap()
+}
+
+// Part 2 - Compose a parser/tokenizer using nom.
+
+/// Parses a single numeric character, 1-9.
+pub fn numeric_numbers(input: & str) -> IResult<&str, u32> {
+    context("written numbers",
+       alt(
+        (value(1,tag_no_case("1")), 
+         value(2, tag_no_case("2")),
+         value(3, tag_no_case("3")),
+         value(4, tag_no_case("4")),
+         value(5, tag_no_case("5")),
+         value(6, tag_no_case("6")),
+         value(7, tag_no_case("7")),
+         value(8, tag_no_case("8")),
+         value(9, tag_no_case("9")),
+        ))
+    )(input)
+}
+
+/// Parses a single english number word, one-nine.
+pub fn english_numbers(input: &str) -> IResult<&str, u32> {
+    context("written numbers",
+       alt(
+        (value(1, tag_no_case("one")), 
+         value(2, tag_no_case("two")),
+         value(3, tag_no_case("three")),
+         value(4, tag_no_case("four")),
+         value(5, tag_no_case("five")),
+         value(6, tag_no_case("six")),
+         value(7, tag_no_case("seven")),
+         value(8, tag_no_case("eight")),
+         value(9, tag_no_case("nine")),
+        ))
+    )(input)
+}
+
+/// Pulls the next token of input.
+/// This will return either: Some(number) or None
+/// via the following in-order patterns:
+/// 1. numeric characters 0-9
+/// 2. numeric words zero -> nine
+/// 3. Any other remaining character
+pub fn token_parser(input: &str) -> IResult<&str, Option<u32>> {
+    alt((
+        map(numeric_numbers, Some),
+        map(english_numbers, Some),
+        map(anychar, |_| None)
+    ))(input)
+}
+
+/// Parses a Roman numeral i-ix that ends its word, so the "vi" of "vivid"
+/// doesn't count. Whether a word starts here is up to the caller, who knows
+/// what came before; see [`roman_at`].
+pub fn roman_numerals(input: &str) -> IResult<&str, u32> {
+    context("roman numerals",
+      terminated(
+       alt(
+        (value(8, tag_no_case("viii")),
+         value(7, tag_no_case("vii")),
+         value(3, tag_no_case("iii")),
+         value(9, tag_no_case("ix")),
+         value(4, tag_no_case("iv")),
+         value(6, tag_no_case("vi")),
+         value(2, tag_no_case("ii")),
+         value(5, tag_no_case("v")),
+         value(1, tag_no_case("i")),
+        )),
+       not(satisfy(char::is_alphabetic)))
+    )(input)
+}
+
+/// The value and length of the Roman numeral making up the whole word that
+/// starts `at` in `line`, if there is one.
+pub fn roman_at(line: &str, at: usize) -> Option<(u32, usize)> {
+    if line[..at].chars().next_back().is_some_and(char::is_alphabetic) {
+        return None;
+    }
+    let (rest, value) = roman_numerals(&line[at..]).ok()?;
+    Some((value, line.len() - at - rest.len()))
+}
+
+/// Parses a single digit or number word of `vocabulary`, like
+/// [`numeric_numbers`] and [`english_numbers`] together. Words in several
+/// languages may start alike, so the longest one wins.
+pub fn vocabulary_numbers(vocabulary: &Vocabulary) -> impl Fn(&str) -> IResult<&str, u32> + '_ {
+    move |input| {
+        if let Some((value, len)) = vocabulary.word_at(input) {
+            return Ok((&input[len..], value));
+        }
+        let (rest, c) = anychar(input)?;
+        match vocabulary.digit(c) {
+            Some(digit) => Ok((rest, digit)),
+            None => Err(nom::Err::Error(Error::new(input, ErrorKind::Digit))),
+        }
+    }
+}
+
+/// How a line is split into tokens.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tokenizer {
+    /// A number word is used up once matched, so "oneight" is just 1.
+    Consuming,
+    /// A number word may start at every character, so "oneight" is 1 then 8.
+    Overlapping,
+}
+
+/// Every digit in a line, in order, as split up by `tokenizer`.
+pub fn tokenize(line: &str, tokenizer: Tokenizer) -> Vec<u32> {
+    tokenize_with(line, tokenizer, &Vocabulary::default())
+}
+
+/// Every digit and number word of `vocabulary` in a line, in order.
+pub fn tokenize_with(line: &str, tokenizer: Tokenizer, vocabulary: &Vocabulary) -> Vec<u32> {
+    tokens_with(line, tokenizer, vocabulary).iter().map(|token| token.value).collect()
+}
+
+/// Whether a token was written as a digit, a word or a Roman numeral.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kind {
+    Digit,
+    Word,
+    Roman,
+}
+
+/// A digit or number word, and where it is in its line.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Token {
+    /// The bytes of the line it was read from.
+    pub span: Range<usize>,
+    pub kind: Kind,
+    pub value: u32,
+}
+
+/// Every digit and number word of `vocabulary` in a line, in order, with
+/// where each one is.
+pub fn tokens_with<'a>(line: &'a str, tokenizer: Tokenizer, vocabulary: &Vocabulary) -> Vec<Token> {
+    if line.is_empty() {
+        return Vec::new();
+    }
+    let numbers = vocabulary_numbers(vocabulary);
+    let located = |input: &'a str| {
+        let at = line.len() - input.len();
+        if let Some((value, len)) = vocabulary.roman_at(line, at) {
+            return Ok((&input[len..], Token { span: at..at + len, kind: Kind::Roman, value }));
+        }
+        map(consumed(&numbers), move |(text, value): (&str, u32)| Token {
+            span: at..at + text.len(),
+            kind: if vocabulary.word_at(text).is_some() { Kind::Word } else { Kind::Digit },
+            value,
+        })(input)
+    };
+    let token = |input| match tokenizer {
+        Tokenizer::Consuming => alt((map(located, Some), map(anychar, |_| None)))(input),
+        Tokenizer::Overlapping => {
+            let (input, token) = peek(opt(located))(input)?;
+            let (input, _) = anychar(input)?;
+            Ok((input, token))
+        },
+    };
+    let (rest, result) = many1(token)(line).unwrap();
+    assert_eq!(rest, "");
+    result.into_iter().flatten().collect()
+}
+
+/// Part two's tokenizer + parser: decodes a line using both numeric
+/// characters and english number words, e.g. `two1nine` is 29.
+pub fn decode_line2(line: &str) -> u32 {
+    decode_line2_with(line, Tokenizer::Consuming, &Vocabulary::default())
+}
+
+/// Decodes a line like [`decode_line2`], but with overlapping number words,
+/// e.g. `oneight` is 18.
+pub fn decode_line_overlapping(line: &str) -> u32 {
+    decode_line2_with(line, Tokenizer::Overlapping, &Vocabulary::default())
+}
+
+/// Decodes a line with both digits and number words of `vocabulary`.
+pub fn decode_line2_with(line: &str, tokenizer: Tokenizer, vocabulary: &Vocabulary) -> u32 {
+    let collapsed = tokenize_with(line, tokenizer, vocabulary);
+    let value = first_and_last(&collapsed);
+    explain::event!("decode", format!("{line} results in {value}"), line, digits = collapsed, value);
+    value
+}
+
+/// Part two: sums the value of every line, including number words.
+pub fn decode_msg(msg: &str) -> u32 {
+    calibration_sum(decode_msg_with(msg, &Words::english(Tokenizer::Consuming)))
+}
+
+/// Part two, with overlapping number words.
+pub fn decode_msg_overlapping(msg: &str) -> u32 {
+    calibration_sum(decode_msg_with(msg, &Words::english(Tokenizer::Overlapping)))
+}
+
+/// Decodes a line reading compound number phrases as their full value, so
+/// each phrase gives its own first and last digits, e.g. `twenty-one` is 21
+/// and `x3yone hundred` is 30.
+pub fn decode_line_phrases(line: &str) -> u32 {
+    let digits = Phrases.digits(line);
+    let value = first_and_last(&digits);
+    explain::event!("decode", format!("{line} results in {value}"), line, digits, value);
+    value
+}
+
+/// Part two, with compound number phrases.
+pub fn decode_msg_phrases(msg: &str) -> u32 {
+    calibration_sum(decode_msg_with(msg, &Phrases))
+}
+
+// Part 2 - again, scanning each line by hand.
+const NUMBER_WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+// Every digit in a line, checking each position by hand.
+fn scan_digits(line: &str, tokenizer: Tokenizer) -> Vec<u32> {
+    let mut digits = Vec::new();
+    let mut rest = line;
+    while let Some(c) = rest.chars().next() {
+        let word = NUMBER_WORDS.iter().position(|word| {
+            rest.get(..word.len()).is_some_and(|start| start.eq_ignore_ascii_case(word))
+        });
+        let skip = match (unicode::decimal_value(c).filter(|digit| *digit > 0), word) {
+            (Some(digit), _) => {
+                digits.push(digit);
+                c.len_utf8()
+            },
+            (_, Some(idx)) => {
+                digits.push(idx as u32 + 1);
+                match tokenizer {
+                    Tokenizer::Consuming => NUMBER_WORDS[idx].len(),
+                    Tokenizer::Overlapping => 1,
+                }
+            },
+            _ => c.len_utf8(),
+        };
+        rest = &rest[skip..];
+    }
+    digits
+}
+
+// Two digits always fit.
+fn first_and_last(digits: &[u32]) -> u32 {
+    combination::Rule::FirstAndLast.combine(digits).unwrap() as u32
+}
+
+/// Decodes a line just like [`decode_line2`], but checks each position for
+/// a digit or number word by hand instead of with nom.
+pub fn decode_line_scan(line: &str) -> u32 {
+    first_and_last(&scan_digits(line, Tokenizer::Consuming))
+}
+
+/// Decodes a line just like [`decode_line_overlapping`], by hand.
+pub fn decode_line_scan_overlapping(line: &str) -> u32 {
+    first_and_last(&scan_digits(line, Tokenizer::Overlapping))
+}
+
+/// Part two, decoding each line with [`decode_line_scan`].
+pub fn decode_msg_scan(msg: &str) -> u32 {
+    msg.lines().map(decode_line_scan).sum()
+}
+
+/// Part two, decoding each line with [`decode_line_scan_overlapping`].
+pub fn decode_msg_scan_overlapping(msg: &str) -> u32 {
+    msg.lines().map(decode_line_scan_overlapping).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::combination::Rule;
+
+    #[test]
+    fn it_works() {
+        let result = 
+        decode_msg("1abc2\n\
+                    pqr3stu8vwx\n\
+                    a1b2c3d4e5f\n\
+                    treb7uchet");
+        assert_eq!(result, 142);
+    }
+
+    #[test]
+    fn part_two_works() {
+        let result =
+        decode_msg("two1nine\n\
+            eightwothree\n\
+            abcone2threexyz\n\
+            xtwone3four\n\
+            4nineeightseven2\n\
+            zoneight234\n\
+            7pqrstsixteen");
+        assert_eq!(result, 281)
+    }
+
+    #[test]
+    fn part_two_helpers() {
+        let result =
+          decode_msg("1fooo2\n\
+                      twoandthree\n\
+                      a123459");
+        assert_eq!(result, 12+23+19);
+    }
+    #[test]
+    fn part_two_english_parser() {
+        assert_eq!(english_numbers("one"), Ok(("", 1)));
+        assert_eq!(english_numbers("twoalpha"), Ok(("alpha", 2)));
+        assert_eq!(english_numbers("three"), Ok(("", 3)));
+        assert_eq!(english_numbers("seveneightnine"), Ok(("eightnine", 7)));
+    }
+    #[test]
+    fn part_two_number_parser() {
+        assert_eq!(numeric_numbers("1"), Ok(("", 1)));
+        assert_eq!(numeric_numbers("2alpha"), Ok(("alpha", 2)));
+        assert_eq!(numeric_numbers("3"), Ok(("", 3)));
+        assert_eq!(numeric_numbers("789"), Ok(("89", 7)));
+    }
+
+    #[test]
+    fn part_two_explain() {
+        let (result, events) = explain::capture(|| decode_line2("xtwone3four"));
+        assert_eq!(result, 24);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].fields[1], (String::from("digits"), String::from("[2, 3, 4]")));
+    }
+
+    #[test]
+    fn part_two_token_parser() {
+        assert_eq!(token_parser("1twothreefour"), Ok(("twothreefour", Some(1))));
+        assert_eq!(token_parser("twoalpha"), Ok(("alpha", Some(2))));
+        assert_eq!(token_parser("ab13"), Ok(("b13", None)));
+    }
+
+    #[test]
+    fn part_two_roman_token_parser() {
+        assert_eq!(roman_numerals("iv"), Ok(("", 4)));
+        assert_eq!(roman_numerals("VIII."), Ok((".", 8)));
+        assert_eq!(roman_numerals("ix3"), Ok(("3", 9)));
+        assert_eq!(roman_numerals("i am"), Ok((" am", 1)));
+        // Only whole words count.
+        assert!(roman_numerals("vivid").is_err());
+        assert!(roman_numerals("xiv").is_err());
+        assert!(roman_numerals("iiii").is_err());
+        assert_eq!(roman_at("part iv", 5), Some((4, 2)));
+        assert_eq!(roman_at("vivid", 2), None);
+        assert_eq!(roman_at("3vi", 1), Some((6, 2)));
+        assert_eq!(roman_at("éiv", 2), None);
+    }
+
+    #[test]
+    fn part_two_roman_tokens() {
+        let roman = Vocabulary::english().with_roman();
+        assert_eq!(tokenize_with("chapter iv, verse 2", Tokenizer::Consuming, &roman), vec!(4, 2));
+        assert_eq!(tokenize_with("a vivid ix-one", Tokenizer::Overlapping, &roman), vec!(9, 1));
+        assert_eq!(tokenize_with("VII", Tokenizer::Overlapping, &roman), vec!(7));
+        // Without asking for them, numerals are just letters.
+        assert_eq!(tokenize_with("chapter iv, verse 2", Tokenizer::Consuming, &Vocabulary::english()), vec!(2));
+        let tokens = tokens_with("x vi", Tokenizer::Consuming, &roman);
+        assert_eq!(tokens, vec!(Token { span: 2..4, kind: Kind::Roman, value: 6 }));
+    }
+
+    #[test]
+    fn part_two_overlapping() {
+        assert_eq!(decode_line_overlapping("oneight"), 18);
+        assert_eq!(decode_line_overlapping("twone"), 21);
+        assert_eq!(decode_line_overlapping("eightwo"), 82);
+        assert_eq!(decode_line_overlapping("sevenine"), 79);
+        assert_eq!(decode_line_overlapping("xtwone3four"), 24);
+        assert_eq!(tokenize("eightwothree", Tokenizer::Overlapping), vec!(8, 2, 3));
+        assert_eq!(tokenize("twoneight", Tokenizer::Overlapping), vec!(2, 1, 8));
+        assert_eq!(tokenize("twoneight", Tokenizer::Consuming), vec!(2, 8));
+        // Consuming the word hides the digit sharing its last letter.
+        assert_eq!(decode_line2("oneight"), 11);
+    }
+
+    #[test]
+    fn zero_vocabulary() {
+        let zero = Vocabulary::english().with_zero();
+        // Part one reads '0' like any other digit.
+        assert_eq!(decode_line("a0b5c"), 5);
+        assert_eq!(decode_line("a5b0c"), 50);
+        assert_eq!(part_one("a5b0c\n"), 50);
+        // Without zero, '0' and "zero" are just more characters.
+        assert_eq!(decode_line2("zero5zero"), 55);
+        assert_eq!(decode_line2("zero5x0"), 55);
+        assert_eq!(decode_line_scan("zero5x0"), 55);
+        assert_eq!(decode_line_with("a0b5c", &Vocabulary::english()), 55);
+        assert_eq!(decode_line_with("a0b5c", &zero), 5);
+        assert_eq!(decode_line2_with("zero5x0", Tokenizer::Consuming, &zero), 0);
+        assert_eq!(decode_line2_with("zero5zero", Tokenizer::Consuming, &zero), 0);
+        assert_eq!(decode_line2_with("3zero", Tokenizer::Consuming, &zero), 30);
+        assert_eq!(tokenize_with("zerone0", Tokenizer::Overlapping, &zero), vec!(0, 1, 0));
+        // Both parts agree on which characters are digits.
+        for line in ["0abc", "a0b0", "10x01"] {
+            assert_eq!(decode_line_with(line, &zero), decode_line2_with(line, Tokenizer::Consuming, &zero), "{line}");
+        }
+    }
+
+    #[test]
+    fn localized_vocabulary() {
+        let vocabulary = Vocabulary::for_locales("fr,de").unwrap();
+        assert_eq!(decode_line2_with("aDEUXbtrois", Tokenizer::Consuming, &vocabulary), 23);
+        assert_eq!(decode_line2_with("xfÜnfy", Tokenizer::Consuming, &vocabulary), 55);
+        assert_eq!(decode_line2_with("neunhuit", Tokenizer::Overlapping, &vocabulary), 98);
+        // "uno" is one word, not "un" then an "o".
+        let spanish = Vocabulary::for_locales("fr,es").unwrap();
+        assert_eq!(tokenize_with("unocho", Tokenizer::Consuming, &spanish), vec!(1));
+        assert_eq!(tokenize_with("unocho", Tokenizer::Overlapping, &spanish), vec!(1, 8));
+    }
+
+    #[test]
+    fn combination_rules() {
+        let msg = "two1nine\n4nineeightseven2\nxyz\n";
+        let words = Words::english(Tokenizer::Consuming);
+        assert_eq!(decode_msg_with(msg, &words), Ok(29 + 42));
+        assert_eq!(decode_msg_with(msg, &words.clone().with_rule(Rule::Concatenate)), Ok(219 + 49872));
+        assert_eq!(decode_msg_with(msg, &words.clone().with_rule(Rule::Sum)), Ok(12 + 30));
+        assert_eq!(decode_msg_with(msg, &words.clone().with_rule(Rule::Ends(2))), Ok(2119 + 4972));
+        let long = format!("1\n{}\n", "9".repeat(25));
+        assert_eq!(decode_msg_with(&long, &Digits.with_rule(Rule::Concatenate)), Err(format!("line 2: the value of {} is too big", "9".repeat(25))));
+        let big = format!("{0}\n{0}\n", "9".repeat(19));
+        assert_eq!(decode_msg_with(&big, &Digits.with_rule(Rule::Concatenate)), Err(String::from("line 2: the sum is too big")));
+    }
+
+    #[test]
+    fn unicode_digits() {
+        // Decimal digits from other scripts.
+        assert_eq!(decode_line("a٣b"), 33);
+        assert_eq!(decode_line("１x٧"), 17);
+        assert_eq!(decode_line("𝟐𝟑"), 23);
+        // Numbers that aren't digits are skipped rather than panicking.
+        assert_eq!(decode_line("½4²"), 44);
+        assert_eq!(decode_line("Ⅻ①"), 0);
+        // Zeroes from other scripts count in part one, and only with zero in
+        // part two.
+        assert_eq!(decode_line("٠5"), 5);
+        assert_eq!(decode_line2("٠5"), 55);
+        assert_eq!(decode_line2_with("٠5", Tokenizer::Consuming, &Vocabulary::english().with_zero()), 5);
+        // Part two reads them too, however it's scanned.
+        for line in ["two٣", "９abc½", "x³"] {
+            assert_eq!(decode_line2(line), decode_line_scan(line), "{line}");
+        }
+        assert_eq!(decode_line_overlapping("eigh٣twone"), 31);
+        assert_eq!(decode_line_scan_overlapping("eigh٣twone"), 31);
+    }
+
+    #[test]
+    fn unicode_explained() {
+        let (_, events) = explain::capture(|| decode_line("a½1"));
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1].fields[1], (String::from("ignored"), String::from("[(1, '½')]")));
+        // Part one explains them too.
+        let (result, events) = explain::capture(|| part_one("a½1\n²3\n"));
+        assert_eq!(result, 11 + 33);
+        let ignored: Vec<&str> = events.iter().filter(|event| event.stage == "ignore").map(|event| event.fields[1].1.as_str()).collect();
+        assert_eq!(ignored, vec!("[(1, '½')]", "[(0, '²')]"));
+        // Once per line, by whatever sums it, not by reading its tokens.
+        assert!(explain::capture(|| Digits.tokens("a½1")).1.is_empty());
+        let (_, events) = explain::capture(|| report::report("a½1\n", &Digits));
+        assert_eq!(events.iter().filter(|event| event.stage == "ignore").count(), 0);
+        let (_, events) = explain::capture(|| decode_msg("a½one\n"));
+        assert_eq!(events.iter().filter(|event| event.stage == "ignore").count(), 1);
+    }
+
+    #[test]
+    fn part_two_phrases() {
+        assert_eq!(decode_line_phrases("twenty-one"), 21);
+        assert_eq!(decode_line_phrases("thirteen"), 13);
+        assert_eq!(decode_line_phrases("x3yone hundred"), 30);
+        assert_eq!(decode_line_phrases("seventy"), 70);
+        assert_eq!(decode_line_phrases("ab"), 0);
+        // Single number words still decode as before.
+        assert_eq!(decode_msg_phrases("two1nine\neightwothree\n4nineeightseven2\n"), 29 + 83 + 42);
+    }
+
+    #[test]
+    fn part_two_vocabulary_numbers() {
+        let english = Vocabulary::english();
+        assert_eq!(vocabulary_numbers(&english)("Sevenx"), Ok(("x", 7)));
+        assert_eq!(vocabulary_numbers(&english)("4b"), Ok(("b", 4)));
+        assert!(vocabulary_numbers(&english)("zero").is_err());
+        assert!(vocabulary_numbers(&english)("0").is_err());
+        assert_eq!(vocabulary_numbers(&english.with_zero())("0"), Ok(("", 0)));
+    }
+
+    #[test]
+    fn part_two_scan() {
+        for line in ["two1nine", "eightwothree", "xtwone3four", "zoneight234", "7pqrstsixteen", "FiVe0x", "é3", "nothing"] {
+            assert_eq!(decode_line_scan(line), decode_line2(line), "{line}");
+        }
+        assert_eq!(decode_msg_scan("two1nine\nabcone2threexyz"), 29 + 13);
+        for line in ["oneight", "twone", "eightwo", "sevenine", "zoneight234", "xtwone3four", "é3", "nothing"] {
+            assert_eq!(decode_line_scan_overlapping(line), decode_line_overlapping(line), "{line}");
+        }
+        assert_eq!(decode_msg_overlapping("oneight\ntwone"), decode_msg_scan_overlapping("oneight\ntwone"));
+    }
+}
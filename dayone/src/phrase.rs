@@ -0,0 +1,165 @@
+//! Compound english number phrases, like "twenty-one", "thirteen" or
+//! "one hundred and five", read as their full value.
+use nom::IResult;
+use nom::branch::alt;
+use nom::bytes::complete::{tag, tag_no_case};
+use nom::character::complete::{anychar, space1};
+use nom::combinator::{consumed, map, opt, value};
+use nom::error::context;
+use nom::multi::many1;
+use nom::sequence::{pair, preceded, tuple};
+
+use crate::{english_numbers, numeric_numbers, Kind, Token};
+
+// The words of a phrase may be split by a hyphen or spaces, or run together
+// like the rest of the document.
+fn separator(input: &str) -> IResult<&str, ()> {
+    value((), opt(alt((tag("-"), space1))))(input)
+}
+
+// Between a scale and what follows it, as in "one hundred and five".
+fn and(input: &str) -> IResult<&str, ()> {
+    value((), pair(separator, opt(pair(tag_no_case("and"), separator))))(input)
+}
+
+/// Parses ten-nineteen.
+pub fn teens(input: &str) -> IResult<&str, u32> {
+    context("teens",
+       alt(
+        (value(10, tag_no_case("ten")),
+         value(11, tag_no_case("eleven")),
+         value(12, tag_no_case("twelve")),
+         value(13, tag_no_case("thirteen")),
+         value(14, tag_no_case("fourteen")),
+         value(15, tag_no_case("fifteen")),
+         value(16, tag_no_case("sixteen")),
+         value(17, tag_no_case("seventeen")),
+         value(18, tag_no_case("eighteen")),
+         value(19, tag_no_case("nineteen")),
+        ))
+    )(input)
+}
+
+/// Parses twenty-ninety.
+pub fn tens(input: &str) -> IResult<&str, u32> {
+    context("tens",
+       alt(
+        (value(20, tag_no_case("twenty")),
+         value(30, tag_no_case("thirty")),
+         value(40, tag_no_case("forty")),
+         value(50, tag_no_case("fifty")),
+         value(60, tag_no_case("sixty")),
+         value(70, tag_no_case("seventy")),
+         value(80, tag_no_case("eighty")),
+         value(90, tag_no_case("ninety")),
+        ))
+    )(input)
+}
+
+/// Parses a phrase below one hundred, e.g. "forty-two", "twelve" or "six".
+/// Longer words go first, so "sixteen" isn't read as "six".
+pub fn below_hundred(input: &str) -> IResult<&str, u32> {
+    alt((
+        map(pair(tens, opt(preceded(separator, english_numbers))), |(tens, units)| tens + units.unwrap_or(0)),
+        teens,
+        english_numbers,
+    ))(input)
+}
+
+/// Parses a phrase below one thousand, e.g. "three hundred and twelve".
+pub fn below_thousand(input: &str) -> IResult<&str, u32> {
+    alt((
+        map(
+          tuple((english_numbers, separator, tag_no_case("hundred"), opt(preceded(and, below_hundred)))),
+          |(hundreds, _, _, rest)| hundreds * 100 + rest.unwrap_or(0)),
+        below_hundred,
+    ))(input)
+}
+
+/// Parses a whole number phrase, up to "nine hundred and ninety-nine
+/// thousand nine hundred and ninety-nine".
+pub fn number_phrase(input: &str) -> IResult<&str, u32> {
+    map(
+      pair(below_thousand, opt(preceded(pair(separator, tag_no_case("thousand")), opt(preceded(and, below_thousand))))),
+      |(high, low)| match low {
+          Some(low) => high * 1000 + low.unwrap_or(0),
+          None => high,
+      })(input)
+}
+
+/// Pulls the next token of input: a numeric character, a whole number
+/// phrase, or any other character.
+pub fn phrase_token_parser(input: &str) -> IResult<&str, Option<u32>> {
+    alt((
+        map(numeric_numbers, Some),
+        map(number_phrase, Some),
+        map(anychar, |_| None)
+    ))(input)
+}
+
+/// The value of every digit and number phrase in a line, in order.
+pub fn tokenize_phrases(line: &str) -> Vec<u32> {
+    phrase_tokens(line).iter().map(|token| token.value).collect()
+}
+
+/// Every digit and number phrase in a line, in order, with where each one is.
+pub fn phrase_tokens<'a>(line: &'a str) -> Vec<Token> {
+    if line.is_empty() {
+        return Vec::new();
+    }
+    let located = |input: &'a str| {
+        let at = line.len() - input.len();
+        map(consumed(phrase_token_parser), move |(text, value): (&str, Option<u32>)| value.map(|value| Token {
+            span: at..at + text.len(),
+            kind: if numeric_numbers(text).is_ok() { Kind::Digit } else { Kind::Word },
+            value,
+        }))(input)
+    };
+    let (rest, result) = many1(located)(line).unwrap();
+    assert_eq!(rest, "");
+    result.into_iter().flatten().collect()
+}
+
+/// The decimal digits of a value, most significant first.
+pub fn digits(value: u32) -> Vec<u32> {
+    value.to_string().chars().filter_map(|c| c.to_digit(10)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::phrase::*;
+
+    #[test]
+    fn test_number_phrase() {
+        assert_eq!(number_phrase("twenty-one"), Ok(("", 21)));
+        assert_eq!(number_phrase("Twenty one"), Ok(("", 21)));
+        assert_eq!(number_phrase("thirteen"), Ok(("", 13)));
+        assert_eq!(number_phrase("sixteenx"), Ok(("x", 16)));
+        assert_eq!(number_phrase("seventy"), Ok(("", 70)));
+        assert_eq!(number_phrase("one hundred"), Ok(("", 100)));
+        assert_eq!(number_phrase("onehundredandfive"), Ok(("", 105)));
+        assert_eq!(number_phrase("three hundred and forty-two"), Ok(("", 342)));
+        assert_eq!(number_phrase("two thousand and twelve"), Ok(("", 2012)));
+        assert_eq!(number_phrase("five thousand"), Ok(("", 5000)));
+        // A dangling separator or "and" is left for whatever comes next.
+        assert_eq!(number_phrase("twenty apples"), Ok((" apples", 20)));
+        assert_eq!(number_phrase("one hundred and then"), Ok((" and then", 100)));
+        assert!(number_phrase("hundred").is_err());
+    }
+
+    #[test]
+    fn test_tokenize_phrases() {
+        assert_eq!(tokenize_phrases("a3btwenty-one"), vec!(3, 21));
+        assert_eq!(tokenize_phrases("eighteen7twone"), vec!(18, 7, 2));
+        assert_eq!(tokenize_phrases(""), Vec::<u32>::new());
+        assert_eq!(digits(105), vec!(1, 0, 5));
+    }
+
+    #[test]
+    fn test_phrase_tokens() {
+        assert_eq!(phrase_tokens("a3btwenty-one"), vec!(
+            Token { span: 1..2, kind: Kind::Digit, value: 3 },
+            Token { span: 3..13, kind: Kind::Word, value: 21 },
+        ));
+    }
+}
@@ -0,0 +1,138 @@
+//! A line by line account of a calibration sum, for checking it by hand:
+//! which tokens were taken as each line's first and last digit, where they
+//! are, and which lines had no digits at all.
+use crate::extractor::Extractor;
+use crate::{Kind, Token};
+
+/// How one line of a calibration document was decoded.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineReport {
+    /// Counting from 1.
+    pub number: usize,
+    pub line: String,
+    pub first: Option<Token>,
+    pub last: Option<Token>,
+    /// None if it doesn't fit in a u64.
+    pub value: Option<u64>,
+}
+
+impl LineReport {
+    /// The line with its first token in braces and its last in brackets,
+    /// e.g. `{two}1[nine]`. A lone token only gets the brackets, `treb[7]uchet`.
+    pub fn highlighted(&self) -> String {
+        let (Some(first), Some(last)) = (&self.first, &self.last) else {
+            return self.line.clone();
+        };
+        let mut marks = vec!((last.span.start, '['), (last.span.end, ']'));
+        if first != last {
+            marks.extend([(first.span.start, '{'), (first.span.end, '}')]);
+        }
+        // Where tokens touch, close one before opening the next.
+        marks.sort_by_key(|(at, mark)| (*at, matches!(mark, '{' | '[')));
+        let mut highlighted = String::new();
+        let mut from = 0;
+        for (at, mark) in marks {
+            highlighted += &self.line[from..at];
+            highlighted.push(mark);
+            from = at;
+        }
+        highlighted + &self.line[from..]
+    }
+}
+
+fn describe(token: &Token) -> String {
+    let kind = match token.kind {
+        Kind::Digit => "digit",
+        Kind::Word => "word",
+        Kind::Roman => "roman numeral",
+    };
+    format!("{kind} {} at {}..{}", token.value, token.span.start, token.span.end)
+}
+
+/// How every line of `msg` decodes, as read by `extractor`.
+pub fn line_reports(msg: &str, extractor: &impl Extractor) -> Vec<LineReport> {
+    msg.lines().enumerate().map(|(idx, line)| {
+        let tokens = extractor.tokens(line);
+        let value = extractor.decode_line(line);
+        LineReport { number: idx + 1, line: String::from(line), first: tokens.first().cloned(), last: tokens.last().cloned(), value }
+    }).collect()
+}
+
+/// A table of every line of `msg`, its value and the tokens it came from,
+/// flagging lines without digits, then the sum.
+pub fn report(msg: &str, extractor: &impl Extractor) -> String {
+    let reports = line_reports(msg, extractor);
+    let highlighted: Vec<String> = reports.iter().map(LineReport::highlighted).collect();
+    let width = highlighted.iter().map(|line| line.chars().count()).max().unwrap_or(0).max(13);
+    let mut table = format!("line  value  {:width$}  tokens\n", "{first}[last]");
+    for (report, highlighted) in reports.iter().zip(&highlighted) {
+        let (value, tokens) = match (&report.first, &report.last) {
+            (Some(first), Some(last)) => {
+                let value = report.value.map_or(String::from("too big"), |value| value.to_string());
+                (value, format!("first {}, last {}", describe(first), describe(last)))
+            },
+            _ => (String::from("-"), String::from("NO DIGITS")),
+        };
+        table += &format!("{:>4}  {value:>5}  {highlighted:width$}  {tokens}\n", report.number);
+    }
+    let skipped = reports.iter().filter(|report| report.first.is_none()).count();
+    let sum = reports.iter().try_fold(0u64, |sum, report| sum.checked_add(report.value?));
+    let sum = sum.map_or(String::from("too big"), |sum| sum.to_string());
+    table + &format!("sum {sum} over {} lines, {skipped} without digits\n", reports.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::*;
+    use crate::combination::Rule;
+    use crate::extractor::{Phrases, Words};
+    use crate::Tokenizer;
+
+    fn line_report(line: &str, tokenizer: Tokenizer) -> LineReport {
+        line_reports(line, &Words::english(tokenizer)).remove(0)
+    }
+
+    #[test]
+    fn test_line_reports() {
+        let report = line_report("two1nine", Tokenizer::Consuming);
+        assert_eq!(report.first, Some(Token { span: 0..3, kind: Kind::Word, value: 2 }));
+        assert_eq!(report.last, Some(Token { span: 4..8, kind: Kind::Word, value: 9 }));
+        assert_eq!(report.value, Some(29));
+        assert_eq!(line_report("treb7uchet", Tokenizer::Consuming).first, Some(Token { span: 4..5, kind: Kind::Digit, value: 7 }));
+        assert_eq!(line_report("abc", Tokenizer::Consuming).first, None);
+        let phrases = line_reports("x twenty-one", &Phrases).remove(0);
+        assert_eq!(phrases.first, Some(Token { span: 2..12, kind: Kind::Word, value: 21 }));
+        assert_eq!(phrases.highlighted(), "x [twenty-one]");
+    }
+
+    #[test]
+    fn test_highlighted() {
+        assert_eq!(line_report("two1nine", Tokenizer::Consuming).highlighted(), "{two}1[nine]");
+        assert_eq!(line_report("treb7uchet", Tokenizer::Consuming).highlighted(), "treb[7]uchet");
+        assert_eq!(line_report("1two", Tokenizer::Consuming).highlighted(), "{1}[two]");
+        // Overlapping tokens may share letters.
+        assert_eq!(line_report("oneight", Tokenizer::Overlapping).highlighted(), "{on[e}ight]");
+        assert_eq!(line_report("abc", Tokenizer::Consuming).highlighted(), "abc");
+    }
+
+    #[test]
+    fn test_report() {
+        assert_eq!(report("two1nine\nabc\ntreb7uchet\n", &Words::english(Tokenizer::Consuming)),
+          "line  value  {first}[last]  tokens\n   \
+              1     29  {two}1[nine]   first word 2 at 0..3, last word 9 at 4..8\n   \
+              2      -  abc            NO DIGITS\n   \
+              3     77  treb[7]uchet   first digit 7 at 4..5, last digit 7 at 4..5\n\
+           sum 106 over 3 lines, 1 without digits\n");
+    }
+
+    #[test]
+    fn test_rules() {
+        let concatenate = Words::english(Tokenizer::Consuming).with_rule(Rule::Concatenate);
+        assert_eq!(line_reports("two1nine", &concatenate).remove(0).value, Some(219));
+        let long = format!("1\n{}\n", "9".repeat(25));
+        let reports = line_reports(&long, &concatenate);
+        assert_eq!(reports[1].value, None);
+        assert!(report(&long, &concatenate).ends_with("sum too big over 2 lines, 0 without digits\n"));
+        assert!(report(&long, &concatenate).contains("   2  too big  "));
+    }
+}
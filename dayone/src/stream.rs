@@ -0,0 +1,179 @@
+//! Decoding calibration documents too big to comfortably hold in memory, a
+//! line at a time as they're read, or in line-aligned chunks summed in
+//! parallel. Either way the answer, or the error, is just what
+//! [`crate::decode_msg_with`] gives for the whole document.
+use rayon::prelude::*;
+use std::io::BufRead;
+
+use crate::decode_lines;
+use crate::extractor::Extractor;
+
+/// How much of a document each chunk holds when reading it in parallel, when
+/// nobody says otherwise.
+pub const CHUNK_BYTES: usize = 1 << 20;
+
+// A line as `str::lines` gives it: without its `\n`, or `\r\n`.
+fn trim_line_ending(line: &str) -> &str {
+    match line.strip_suffix('\n') {
+        Some(line) => line.strip_suffix('\r').unwrap_or(line),
+        None => line,
+    }
+}
+
+/// Sums every line of a document as it's read, holding only one line at a
+/// time.
+pub fn decode_reader(mut reader: impl BufRead, extractor: &impl Extractor) -> Result<u64, String> {
+    let mut sum = 0;
+    let mut line = String::new();
+    for number in 0.. {
+        line.clear();
+        match reader.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => sum = decode_lines(trim_line_ending(&line), number, sum, extractor)?,
+            Err(e) => return Err(format!("line {}: {e}", number + 1)),
+        }
+    }
+    Ok(sum)
+}
+
+/// Splits a document into about `count` chunks, each ending at the end of a
+/// line, with the number of lines before each.
+pub fn chunks(msg: &str, count: usize) -> Vec<(usize, &str)> {
+    let size = msg.len().div_ceil(count.max(1)).max(1);
+    let mut chunks = Vec::new();
+    let mut lines = 0;
+    let mut rest = msg;
+    while !rest.is_empty() {
+        let end = match rest.as_bytes().get(size..).and_then(|after| after.iter().position(|b| *b == b'\n')) {
+            Some(newline) => size + newline + 1,
+            None => rest.len(),
+        };
+        let (chunk, after) = rest.split_at(end);
+        chunks.push((lines, chunk));
+        lines += chunk.lines().count();
+        rest = after;
+    }
+    chunks
+}
+
+// Adds up chunks decoded in parallel, in order. A chunk that failed, or
+// that would take the sum too far, is decoded again after the ones before
+// it, so it fails on the same line as decoding the document in one go.
+fn sum_chunks(chunks: &[(usize, &str)], sum: u64, extractor: &(impl Extractor + Sync)) -> Result<u64, String> {
+    let sums: Vec<Result<u64, String>> = chunks.par_iter().map(|(first, chunk)| decode_lines(chunk, *first, 0, extractor)).collect();
+    chunks.iter().zip(sums).try_fold(sum, |sum, ((first, chunk), chunk_sum)| {
+        match chunk_sum.ok().and_then(|chunk_sum| sum.checked_add(chunk_sum)) {
+            Some(sum) => Ok(sum),
+            None => decode_lines(chunk, *first, sum, extractor),
+        }
+    })
+}
+
+/// Sums a document in parallel, a chunk of lines per task.
+pub fn decode_parallel(msg: &str, extractor: &(impl Extractor + Sync)) -> Result<u64, String> {
+    sum_chunks(&chunks(msg, rayon::current_num_threads() * 4), 0, extractor)
+}
+
+/// Sums a document as it's read, a batch of line-aligned chunks of about
+/// `chunk_bytes` at a time, each batch in parallel. Only one batch, a chunk
+/// per thread, is held at a time.
+pub fn decode_reader_parallel(mut reader: impl BufRead, extractor: &(impl Extractor + Sync), chunk_bytes: usize) -> Result<u64, String> {
+    let mut sum = 0;
+    let mut lines = 0;
+    loop {
+        let mut batch: Vec<(usize, String)> = Vec::new();
+        while batch.len() < rayon::current_num_threads() {
+            let mut chunk = Vec::new();
+            while chunk.len() < chunk_bytes {
+                match reader.read_until(b'\n', &mut chunk) {
+                    Ok(0) => break,
+                    Ok(_) => (),
+                    Err(e) => return Err(format!("line {}: {e}", lines + chunk.iter().filter(|b| **b == b'\n').count() + 1)),
+                }
+            }
+            if chunk.is_empty() {
+                break;
+            }
+            let chunk = String::from_utf8(chunk).map_err(|e| format!("after line {lines}: {e}"))?;
+            let first = lines;
+            lines += chunk.lines().count();
+            batch.push((first, chunk));
+        }
+        if batch.is_empty() {
+            return Ok(sum);
+        }
+        let chunks: Vec<(usize, &str)> = batch.iter().map(|(first, chunk)| (*first, chunk.as_str())).collect();
+        sum = sum_chunks(&chunks, sum, extractor)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stream::*;
+    use crate::combination::Rule;
+    use crate::extractor::{Digits, Words};
+    use crate::{decode_msg_with, Tokenizer};
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+    use std::io::{BufReader, Cursor};
+
+    // A document with blank lines, lines without digits and `\r\n` endings,
+    // and maybe no newline at the very end.
+    fn document(rng: &mut ChaCha8Rng, lines: usize) -> String {
+        const FRAGMENTS: [&str; 8] = ["one", "7", "eightwo", "x", "", "4nine", "\r", "three"];
+        let mut document: String = (0..lines).map(|_| {
+            let line: String = (0..rng.gen_range(0..4)).map(|_| FRAGMENTS[rng.gen_range(0..FRAGMENTS.len())]).collect();
+            line + if rng.gen_bool(0.2) { "\r\n" } else { "\n" }
+        }).collect();
+        if rng.gen_bool(0.5) {
+            document.pop();
+        }
+        document
+    }
+
+    #[test]
+    fn test_chunks() {
+        let msg = "one\ntwo\nthree\nfour";
+        let chunks = chunks(msg, 3);
+        assert_eq!(chunks, vec!((0, "one\ntwo\n"), (2, "three\nfour")));
+        assert_eq!(chunks.iter().map(|(_, chunk)| *chunk).collect::<String>(), msg);
+        assert_eq!(super::chunks("", 4), vec!());
+        assert_eq!(super::chunks("a\nb\n", 100), vec!((0, "a\n"), (1, "b\n")));
+    }
+
+    #[test]
+    fn test_same_as_decode_msg() {
+        let words = Words::english(Tokenizer::Overlapping);
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        for lines in [0, 1, 2, 10, 500] {
+            let msg = document(&mut rng, lines);
+            let expected = decode_msg_with(&msg, &words);
+            assert_eq!(decode_reader(Cursor::new(&msg), &words), expected, "{msg:?}");
+            assert_eq!(decode_reader(BufReader::with_capacity(3, msg.as_bytes()), &words), expected, "{msg:?}");
+            assert_eq!(decode_parallel(&msg, &words), expected, "{msg:?}");
+            for chunk_bytes in [1, 7, 64, CHUNK_BYTES] {
+                assert_eq!(decode_reader_parallel(Cursor::new(&msg), &words, chunk_bytes), expected, "{msg:?}");
+            }
+        }
+        assert_eq!(decode_parallel("1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet", &Digits), Ok(142));
+    }
+
+    #[test]
+    fn test_same_errors() {
+        let concatenate = Digits.with_rule(Rule::Concatenate);
+        let big = "9".repeat(19);
+        // The value of line 3 is too big, and the sum overflows on line 5.
+        let msgs = [
+            format!("1\n2\n{}\n4\n", "9".repeat(25)),
+            format!("1\n2\n3\n{big}\n{big}\n{big}\n"),
+        ];
+        for msg in &msgs {
+            let expected = decode_msg_with(msg, &concatenate);
+            assert!(expected.is_err());
+            assert_eq!(decode_reader(Cursor::new(msg), &concatenate), expected);
+            assert_eq!(decode_parallel(msg, &concatenate), expected);
+            assert_eq!(decode_reader_parallel(Cursor::new(msg), &concatenate, 2), expected);
+        }
+        assert_eq!(decode_reader(Cursor::new(b"1\n\xff\n"), &concatenate).unwrap_err().split(':').next(), Some("line 2"));
+    }
+}
@@ -0,0 +1,130 @@
+//! Summing a calibration document without letting lines that have no digits
+//! quietly count as 0.
+use std::fmt;
+
+use crate::extractor::{Digits, Extractor, Words};
+use crate::Tokenizer;
+
+/// What to do about lines without any digits.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Mode {
+    /// Fail, naming every such line.
+    Strict,
+    /// Count them as 0, as [`crate::part_one`] and [`crate::decode_msg`] do,
+    /// but say how many there were.
+    Lenient,
+}
+
+/// The sum of a calibration document, and how many lines it left out.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Calibration {
+    pub sum: u64,
+    pub skipped: usize,
+}
+
+/// The lines of a calibration document without any digits.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MissingDigits {
+    /// Counting from 1.
+    pub lines: Vec<usize>,
+}
+
+impl fmt::Display for MissingDigits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let lines: Vec<String> = self.lines.iter().map(usize::to_string).collect();
+        let plural = if self.lines.len() == 1 { "" } else { "s" };
+        write!(f, "No digits on line{plural} {}", lines.join(", "))
+    }
+}
+
+/// Why a calibration document has no sum.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// Lines without any digits, in strict mode.
+    MissingDigits(MissingDigits),
+    /// A line's value doesn't fit in a u64. Its line counts from 1.
+    ValueTooBig { line: usize, text: String },
+    /// The sum doesn't fit in a u64 once a line's value is added to it.
+    SumTooBig { line: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::MissingDigits(missing) => missing.fmt(f),
+            Error::ValueTooBig { line, text } => write!(f, "line {line}: the value of {text} is too big"),
+            Error::SumTooBig { line } => write!(f, "line {line}: the sum is too big"),
+        }
+    }
+}
+
+/// Sums the value of every line, as read and combined by `extractor`.
+pub fn sum_lines(msg: &str, mode: Mode, extractor: &impl Extractor) -> Result<Calibration, Error> {
+    let mut calibration = Calibration { sum: 0, skipped: 0 };
+    let mut missing = Vec::new();
+    for (idx, line) in msg.lines().enumerate() {
+        let number = idx + 1;
+        let digits = extractor.digits(line);
+        if digits.is_empty() {
+            missing.push(number);
+            continue;
+        }
+        let value = extractor.combine(&digits).ok_or_else(|| Error::ValueTooBig { line: number, text: String::from(line) })?;
+        calibration.sum = calibration.sum.checked_add(value).ok_or(Error::SumTooBig { line: number })?;
+    }
+    calibration.skipped = missing.len();
+    match mode {
+        Mode::Strict if !missing.is_empty() => Err(Error::MissingDigits(MissingDigits { lines: missing })),
+        _ => Ok(calibration),
+    }
+}
+
+/// Part one, with lines without digits handled by `mode`.
+pub fn part_one(msg: &str, mode: Mode) -> Result<Calibration, Error> {
+    sum_lines(msg, mode, &Digits)
+}
+
+/// Part two, with lines without digits handled by `mode`.
+pub fn decode_msg(msg: &str, mode: Mode) -> Result<Calibration, Error> {
+    sum_lines(msg, mode, &Words::english(Tokenizer::Consuming))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::strict::*;
+    use crate::combination::Rule;
+
+    const DOCUMENT: &str = "1abc2\nnothing\ntwo1nine\n\nxyz\n";
+
+    #[test]
+    fn test_lenient() {
+        assert_eq!(part_one(DOCUMENT, Mode::Lenient), Ok(Calibration { sum: 12 + 11, skipped: 3 }));
+        assert_eq!(decode_msg(DOCUMENT, Mode::Lenient), Ok(Calibration { sum: 12 + 29, skipped: 3 }));
+        assert_eq!(part_one(DOCUMENT, Mode::Lenient).unwrap().sum, u64::from(crate::part_one(DOCUMENT)));
+        // Strictness only adds the check, the sum is the one without it.
+        let msg = "eightwo\noneight\n";
+        let overlapping = Words::english(Tokenizer::Overlapping);
+        assert_eq!(sum_lines(msg, Mode::Strict, &overlapping).unwrap().sum, u64::from(crate::decode_msg_overlapping(msg)));
+    }
+
+    #[test]
+    fn test_strict() {
+        assert_eq!(part_one(DOCUMENT, Mode::Strict), Err(Error::MissingDigits(MissingDigits { lines: vec!(2, 4, 5) })));
+        // Blank lines count as missing their digits too.
+        assert_eq!(decode_msg(DOCUMENT, Mode::Strict).unwrap_err().to_string(), "No digits on lines 2, 4, 5");
+        assert_eq!(decode_msg("one\nx\n", Mode::Strict).unwrap_err().to_string(), "No digits on line 2");
+        assert_eq!(decode_msg("one\n", Mode::Strict), Ok(Calibration { sum: 11, skipped: 0 }));
+    }
+
+    #[test]
+    fn test_rules() {
+        let concatenate = Digits.with_rule(Rule::Concatenate);
+        assert_eq!(sum_lines("a1b2c3\nx\n45\n", Mode::Lenient, &concatenate), Ok(Calibration { sum: 123 + 45, skipped: 1 }));
+        let long = format!("1\n{}\n", "9".repeat(25));
+        assert_eq!(sum_lines(&long, Mode::Lenient, &concatenate), Err(Error::ValueTooBig { line: 2, text: "9".repeat(25) }));
+        assert_eq!(sum_lines(&long, Mode::Lenient, &concatenate).unwrap_err().to_string(), format!("line 2: the value of {} is too big", "9".repeat(25)));
+        let big = format!("{0}\n{0}\n", "9".repeat(19));
+        assert_eq!(sum_lines(&big, Mode::Strict, &concatenate), Err(Error::SumTooBig { line: 2 }));
+        assert_eq!(sum_lines(&big, Mode::Strict, &concatenate).unwrap_err().to_string(), "line 2: the sum is too big");
+    }
+}
@@ -0,0 +1,123 @@
+//! Digits beyond ASCII: every script's decimal digits, like Arabic-Indic
+//! '٣' or fullwidth '３', and the other numeric characters, like '½' or
+//! '²', which look like numbers but aren't digits.
+
+// The zero of every run of ten decimal digits (general category Nd) as of
+// Unicode 17.0, the version of the standard library's own tables, which
+// `is_numeric` reads. Each run holds its script's 0-9 in order.
+const ZEROES: [u32; 77] = [
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6,
+    0x0C66, 0x0CE6, 0x0D66, 0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0,
+    0x1810, 0x1946, 0x19D0, 0x1A80, 0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620,
+    0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0, 0xFF10, 0x104A0, 0x10D30, 0x10D40,
+    0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0, 0x11650, 0x116C0, 0x116D0,
+    0x116DA, 0x11730, 0x118E0, 0x11950, 0x11BF0, 0x11C50, 0x11D50, 0x11DA0, 0x11DE0, 0x11F50,
+    0x16130, 0x16A60, 0x16AC0, 0x16B50, 0x16D70, 0x1CCF0, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC,
+    0x1D7F6, 0x1E140, 0x1E2F0, 0x1E4F0, 0x1E5F1, 0x1E950, 0x1FBF0,
+];
+
+/// The value of a decimal digit from any script, e.g. 3 for '٣' or '３'.
+pub fn decimal_value(c: char) -> Option<u32> {
+    let code = c as u32;
+    // The last zero at or before `c`, which it belongs to if it's a digit.
+    let zero = match ZEROES.binary_search(&code) {
+        Ok(idx) => ZEROES[idx],
+        Err(0) => return None,
+        Err(idx) => ZEROES[idx - 1],
+    };
+    Some(code - zero).filter(|value| *value < 10)
+}
+
+/// Every decimal digit of every script, with its value.
+pub fn decimal_digits() -> impl Iterator<Item = (char, u32)> {
+    ZEROES.iter().flat_map(|zero| (0..10).map(move |value| (char::from_u32(zero + value).unwrap(), value)))
+}
+
+/// Whether a character is numeric without being a decimal digit, like '½',
+/// '²' or 'Ⅻ'. These can't make up a calibration value.
+pub fn is_other_numeric(c: char) -> bool {
+    c.is_numeric() && decimal_value(c).is_none()
+}
+
+/// Every numeric character in a line that isn't a decimal digit, and where
+/// it is.
+pub fn other_numerics(line: &str) -> Vec<(usize, char)> {
+    line.char_indices().filter(|(_, c)| is_other_numeric(*c)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::unicode::*;
+
+    #[test]
+    fn test_ascii() {
+        for (value, c) in ('0'..='9').enumerate() {
+            assert_eq!(decimal_value(c), Some(value as u32));
+        }
+        assert_eq!(decimal_value('a'), None);
+        assert_eq!(decimal_value('/'), None);
+        assert_eq!(decimal_value(':'), None);
+    }
+
+    #[test]
+    fn test_other_scripts() {
+        // Arabic-Indic, extended Arabic-Indic, Devanagari, fullwidth, and
+        // mathematical bold.
+        assert_eq!(decimal_value('٣'), Some(3));
+        assert_eq!(decimal_value('۷'), Some(7));
+        assert_eq!(decimal_value('९'), Some(9));
+        assert_eq!(decimal_value('３'), Some(3));
+        assert_eq!(decimal_value('０'), Some(0));
+        assert_eq!(decimal_value('𝟗'), Some(9));
+        assert_eq!(decimal_digits().count(), ZEROES.len() * 10);
+        // Every digit is numeric to Rust too.
+        for zero in ZEROES {
+            for value in 0..10 {
+                let c = char::from_u32(zero + value).unwrap();
+                assert!(c.is_numeric(), "{c:?}");
+                assert_eq!(decimal_value(c), Some(value));
+            }
+        }
+    }
+
+    // A toolchain on a newer Unicode may have digits the table doesn't: this
+    // fails until it's brought up to date.
+    #[test]
+    fn test_unicode_version() {
+        assert_eq!(char::UNICODE_VERSION, (17, 0, 0), "ZEROES needs the decimal digits of this Unicode version");
+    }
+
+    // Every run of exactly ten numeric characters is a script's digits, but
+    // for these, which only look like them.
+    const NOT_DIGITS: [u32; 6] = [
+        0x17F0, // Khmer lek attak symbols
+        0x2080, // subscripts
+        0x3220, // parenthesized ideographs
+        0x3280, // circled ideographs
+        0xA6E6, // Bamum letters
+        0x10F1D, // Old Sogdian numbers
+    ];
+
+    #[test]
+    fn test_every_digit() {
+        let numeric = |code: u32| char::from_u32(code).is_some_and(char::is_numeric);
+        for code in 0..=char::MAX as u32 {
+            let run = (code..).take_while(|code| numeric(*code)).take(11).count();
+            if run == 10 && !numeric(code.wrapping_sub(1)) && !NOT_DIGITS.contains(&code) {
+                assert!(ZEROES.contains(&code), "U+{code:04X} starts ten digits missing from ZEROES");
+            }
+        }
+    }
+
+    #[test]
+    fn test_other_numerics() {
+        // Fractions, superscripts, circled numbers and roman numerals.
+        for c in ['½', '²', '³', '①', 'Ⅻ', '፲'] {
+            assert!(is_other_numeric(c), "{c}");
+            assert_eq!(decimal_value(c), None);
+        }
+        assert!(!is_other_numeric('7'));
+        assert!(!is_other_numeric('x'));
+        assert_eq!(other_numerics("a½b3²"), vec!((1, '½'), (5, '²')));
+    }
+}
@@ -0,0 +1,253 @@
+use std::fs;
+use std::path::Path;
+
+use crate::unicode;
+
+/// The digits and number words a calibration document may use.
+///
+/// Zero is left out unless asked for, the digit `0` as much as the word
+/// `zero`, as part two of the puzzle never reads either: see
+/// [`Vocabulary::with_zero`]. Part one reads only digits, `0` among them.
+///
+/// Words come from tables of a value and its words per line, like
+///
+/// ```text
+/// # French
+/// 1 un une
+/// 2 deux
+/// ```
+///
+/// and match whatever their case, accented letters included.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Vocabulary {
+    // Every number word, lower cased, and its value, zero included.
+    words: Vec<(String, u32)>,
+    zero: bool,
+    // Whether Roman numerals i-ix standing as words count.
+    roman: bool,
+}
+
+/// The locales with a table built in, and their tables.
+const LOCALES: [(&str, &str); 4] = [
+    ("en", include_str!("../vocabularies/en.txt")),
+    ("fr", include_str!("../vocabularies/fr.txt")),
+    ("de", include_str!("../vocabularies/de.txt")),
+    ("es", include_str!("../vocabularies/es.txt")),
+];
+
+impl Default for Vocabulary {
+    fn default() -> Self {
+        Vocabulary::english()
+    }
+}
+
+// The length in `input` of `word`, if `input` starts with it whatever the case.
+fn starts_with_no_case(input: &str, word: &str) -> Option<usize> {
+    let mut chars = input.char_indices();
+    for expected in word.chars() {
+        let (_, c) = chars.next()?;
+        if !c.to_lowercase().eq(expected.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(chars.next().map_or(input.len(), |(idx, _)| idx))
+}
+
+// Where `word` starts in `input`, if `input` ends with it whatever the case.
+fn ends_with_no_case(input: &str, word: &str) -> Option<usize> {
+    let mut chars = input.char_indices().rev();
+    let mut start = input.len();
+    for expected in word.chars().rev() {
+        let (idx, c) = chars.next()?;
+        if !c.to_lowercase().eq(expected.to_lowercase()) {
+            return None;
+        }
+        start = idx;
+    }
+    Some(start)
+}
+
+impl Vocabulary {
+    /// The english words one-nine, and the digits 1-9.
+    pub fn english() -> Vocabulary {
+        Vocabulary::for_locale("en").unwrap()
+    }
+
+    /// The built in table of a locale like `fr`, `de_DE` or `es-MX`.
+    pub fn for_locale(locale: &str) -> Result<Vocabulary, String> {
+        let language = locale.split(['-', '_']).next().unwrap_or(locale).to_lowercase();
+        let (_, table) = LOCALES.iter().find(|(name, _)| *name == language)
+          .ok_or(format!("No number words for locale {locale}"))?;
+        Vocabulary::parse(table)
+    }
+
+    /// The built in tables of several locales at once, like `en,fr`.
+    pub fn for_locales(locales: &str) -> Result<Vocabulary, String> {
+        locales.split(',').map(str::trim).try_fold(Vocabulary::empty(), |vocabulary, locale| {
+            Ok(vocabulary.and(Vocabulary::for_locale(locale)?))
+        })
+    }
+
+    /// Only the digits, without any words.
+    pub fn empty() -> Vocabulary {
+        Vocabulary { words: Vec::new(), zero: false, roman: false }
+    }
+
+    /// Reads a table of number words, a value and its words per line.
+    /// Blank lines and lines starting with `#` are skipped.
+    pub fn parse(table: &str) -> Result<Vocabulary, String> {
+        let mut words = Vec::new();
+        for (idx, line) in table.lines().map(str::trim).enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.split_whitespace();
+            let value = fields.next().and_then(|value| value.parse::<u32>().ok()).filter(|value| *value <= 9)
+              .ok_or(format!("line {}: expected a digit, then its words", idx + 1))?;
+            let before = words.len();
+            words.extend(fields.map(|word| (word.to_lowercase(), value)));
+            if words.len() == before {
+                return Err(format!("line {}: no words for {value}", idx + 1));
+            }
+        }
+        Ok(Vocabulary { words, zero: false, roman: false })
+    }
+
+    /// Reads a table of number words from a file, see [`Vocabulary::parse`].
+    pub fn load(path: &Path) -> Result<Vocabulary, String> {
+        let table = fs::read_to_string(path).map_err(|e| format!("Unable to read {}: {e}", path.display()))?;
+        Vocabulary::parse(&table).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    /// The words of both vocabularies, counting zero or Roman numerals if
+    /// either does.
+    pub fn and(mut self, other: Vocabulary) -> Vocabulary {
+        for word in other.words {
+            if !self.words.contains(&word) {
+                self.words.push(word);
+            }
+        }
+        Vocabulary { zero: self.zero || other.zero, roman: self.roman || other.roman, ..self }
+    }
+
+    /// The same vocabulary with `0` and `zero` counting too.
+    pub fn with_zero(self) -> Vocabulary {
+        Vocabulary { zero: true, ..self }
+    }
+
+    pub fn has_zero(&self) -> bool {
+        self.zero
+    }
+
+    /// The same vocabulary with Roman numerals i-ix counting too, when they
+    /// are a whole word: "iv" but not the "vi" of "vivid".
+    pub fn with_roman(self) -> Vocabulary {
+        Vocabulary { roman: true, ..self }
+    }
+
+    pub fn has_roman(&self) -> bool {
+        self.roman
+    }
+
+    /// The value and length of the Roman numeral starting `at` in `line`,
+    /// if they count, see [`crate::roman_at`].
+    pub fn roman_at(&self, line: &str, at: usize) -> Option<(u32, usize)> {
+        crate::roman_at(line, at).filter(|_| self.roman)
+    }
+
+    /// The value of a digit character from any script, if it is one that
+    /// counts: zeroes only do with zero.
+    pub fn digit(&self, c: char) -> Option<u32> {
+        unicode::decimal_value(c).filter(|digit| *digit > 0 || self.zero)
+    }
+
+    /// Every number word in use, with its value.
+    pub fn words(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.words.iter()
+          .filter(|(_, value)| *value > 0 || self.zero)
+          .map(|(word, value)| (word.as_str(), *value))
+    }
+
+    /// The value and length in bytes of the longest number word `input`
+    /// starts with, whatever its case.
+    pub fn word_at(&self, input: &str) -> Option<(u32, usize)> {
+        self.words()
+          .filter_map(|(word, value)| Some((value, starts_with_no_case(input, word)?)))
+          .max_by_key(|(_, len)| *len)
+    }
+
+    /// Where each number word `input` ends with starts, whatever its case.
+    pub fn words_ending<'a>(&'a self, input: &'a str) -> impl Iterator<Item = usize> + 'a {
+        self.words().filter_map(|(word, _)| ends_with_no_case(input, word))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vocabulary::*;
+
+    #[test]
+    fn test_digits() {
+        let english = Vocabulary::english();
+        assert_eq!(english.digit('7'), Some(7));
+        assert_eq!(english.digit('0'), None);
+        assert_eq!(english.digit('٠'), None);
+        assert_eq!(english.digit('x'), None);
+        let zero = english.with_zero();
+        assert_eq!(zero.digit('0'), Some(0));
+        assert_eq!(zero.digit('٠'), Some(0));
+    }
+
+    #[test]
+    fn test_words() {
+        let english = Vocabulary::default();
+        assert_eq!(english.words().next(), Some(("one", 1)));
+        assert_eq!(english.words().count(), 9);
+        assert!(!english.has_zero());
+        let zero = english.with_zero();
+        assert!(zero.has_zero());
+        assert_eq!(zero.words().next(), Some(("zero", 0)));
+    }
+
+    #[test]
+    fn test_locales() {
+        assert_eq!(Vocabulary::for_locale("fr_FR").unwrap().word_at("deux"), Some((2, 4)));
+        assert_eq!(Vocabulary::for_locale("DE").unwrap().word_at("fünfzig"), Some((5, 5)));
+        assert!(Vocabulary::for_locale("xx").is_err());
+        let many = Vocabulary::for_locales("en, es").unwrap();
+        assert_eq!(many.word_at("Seven"), Some((7, 5)));
+        assert_eq!(many.word_at("siete"), Some((7, 5)));
+        assert_eq!(many.word_at("sept"), None);
+    }
+
+    #[test]
+    fn test_word_at() {
+        let french = Vocabulary::for_locale("fr").unwrap().with_zero();
+        // Accented letters match whatever their case.
+        assert_eq!(french.word_at("ZÉRO"), Some((0, 5)));
+        assert_eq!(french.word_at("zÉro!"), Some((0, 5)));
+        assert_eq!(french.word_at("zero"), None);
+        // The longest word wins.
+        assert_eq!(french.word_at("une"), Some((1, 3)));
+        // Without zero, its words don't count.
+        assert_eq!(Vocabulary::for_locale("fr").unwrap().word_at("zéro"), None);
+    }
+
+    #[test]
+    fn test_words_ending() {
+        let many = Vocabulary::for_locales("en,fr").unwrap();
+        assert_eq!(many.words_ending("xoNE").collect::<Vec<_>>(), vec!(1));
+        assert_eq!(many.words_ending("xUnE").collect::<Vec<_>>(), vec!(1));
+        assert_eq!(many.words_ending("deux!").count(), 0);
+        assert_eq!(many.with_zero().words_ending("aZÉRO").collect::<Vec<_>>(), vec!(1));
+    }
+
+    #[test]
+    fn test_parse() {
+        let vocabulary = Vocabulary::parse("# Dutch\n\n1 een\n2 twee\n").unwrap();
+        assert_eq!(vocabulary.words().collect::<Vec<_>>(), vec!(("een", 1), ("twee", 2)));
+        assert!(Vocabulary::parse("one een").is_err());
+        assert!(Vocabulary::parse("10 tien").is_err());
+        assert!(Vocabulary::parse("3").is_err());
+    }
+}
@@ -0,0 +1,120 @@
+//! Structured reasoning traces for solvers.
+//!
+//! Solvers describe how they reach an answer with [`event!`], and callers
+//! that want to see it run the solver inside [`capture`]. Outside of
+//! `capture` events are never built, so tracing costs nothing.
+use std::cell::RefCell;
+
+/// One step of a solver's reasoning.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Event {
+    /// What the solver was doing, e.g. "decode" or "winning seed".
+    pub stage: String,
+    /// A readable summary of the step.
+    pub message: String,
+    /// The values behind the step, in the order they were given.
+    pub fields: Vec<(String, String)>,
+}
+
+thread_local! {
+    static EVENTS: RefCell<Option<Vec<Event>>> = const { RefCell::new(None) };
+}
+
+/// Whether anyone is capturing events on this thread.
+pub fn enabled() -> bool {
+    EVENTS.with(|events| events.borrow().is_some())
+}
+
+/// Records an event, if events are being captured.
+pub fn record(event: Event) {
+    EVENTS.with(|events| {
+        if let Some(events) = events.borrow_mut().as_mut() {
+            events.push(event);
+        }
+    })
+}
+
+/// Runs `f`, returning its result along with every event it recorded.
+pub fn capture<R>(f: impl FnOnce() -> R) -> (R, Vec<Event>) {
+    let outer = EVENTS.with(|events| events.replace(Some(Vec::new())));
+    let result = f();
+    let captured = EVENTS.with(|events| events.replace(outer)).unwrap_or_default();
+    (result, captured)
+}
+
+/// Records a trace event: a stage, a message and any number of
+/// `name = value` fields, where values are shown with `{:?}`.
+///
+/// Nothing is evaluated unless events are being captured.
+///
+/// ```
+/// let (_, events) = explain::capture(|| {
+///     let line = "two1nine";
+///     explain::event!("decode", format!("{line} results in 29"), line, digits = vec![2, 1, 9]);
+/// });
+/// assert_eq!(events[0].fields[1], (String::from("digits"), String::from("[2, 1, 9]")));
+/// ```
+#[macro_export]
+macro_rules! event {
+    ($stage:expr, $message:expr $(, $key:ident $(= $value:expr)?)* $(,)?) => {
+        if $crate::enabled() {
+            $crate::record($crate::Event {
+                stage: ::std::string::String::from($stage),
+                message: ::std::string::ToString::to_string(&$message),
+                fields: vec![$(
+                    (::std::string::String::from(stringify!($key)), format!("{:?}", $crate::event!(@value $key $(= $value)?)))
+                ),*],
+            })
+        }
+    };
+    (@value $key:ident = $value:expr) => { $value };
+    (@value $key:ident) => { $key };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_capture() {
+        let (result, events) = capture(|| {
+            let seed = 13;
+            event!("winning seed", format!("seed {seed} wins"), seed, chain = [13, 52]);
+            42
+        });
+        assert_eq!(result, 42);
+        assert_eq!(events, vec!(Event {
+            stage: String::from("winning seed"),
+            message: String::from("seed 13 wins"),
+            fields: vec!(
+                (String::from("seed"), String::from("13")),
+                (String::from("chain"), String::from("[13, 52]")),
+            ),
+        }));
+    }
+
+    #[test]
+    fn test_disabled() {
+        fn expensive() -> String {
+            panic!("should not be evaluated")
+        }
+        assert!(!enabled());
+        // Would panic if the message were built.
+        event!("stage", expensive());
+        let (_, events) = capture(|| event!("inner", "inside"));
+        assert_eq!(events.len(), 1);
+        assert!(!enabled());
+    }
+
+    #[test]
+    fn test_nested_capture() {
+        let (inner, outer) = capture(|| {
+            event!("outer", "before");
+            let (_, inner) = capture(|| event!("inner", "inside"));
+            event!("outer", "after");
+            inner
+        });
+        assert_eq!(inner.len(), 1);
+        assert_eq!(outer.iter().map(|e| e.message.as_str()).collect::<Vec<_>>(), vec!("before", "after"));
+    }
+}
@@ -0,0 +1,119 @@
+//! Golden file snapshots for textual output.
+//!
+//! [`assert_snapshot!`] compares text against `snapshots/<name>.snap` in the
+//! calling crate, and fails with a line by line diff when they differ. Run
+//! the tests with `UPDATE_SNAPSHOTS=1` to accept the new output instead,
+//! which writes (or rewrites) the golden files.
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The environment variable that accepts new snapshots.
+pub const UPDATE: &str = "UPDATE_SNAPSHOTS";
+
+/// Where a crate keeps a snapshot.
+pub fn path(crate_dir: &str, name: &str) -> PathBuf {
+    Path::new(crate_dir).join("snapshots").join(format!("{name}.snap"))
+}
+
+/// A line diff turning `expected` into `actual`, unchanged lines starting
+/// with a space and changed ones with `-` or `+`.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+    // common[i][j] is the longest common subsequence of old[i..] and new[j..].
+    let mut common = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut diff = String::new();
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            diff += &format!(" {}\n", old[i]);
+            (i, j) = (i + 1, j + 1);
+        } else if j < new.len() && (i == old.len() || common[i][j + 1] > common[i + 1][j]) {
+            diff += &format!("+{}\n", new[j]);
+            j += 1;
+        } else {
+            diff += &format!("-{}\n", old[i]);
+            i += 1;
+        }
+    }
+    diff
+}
+
+/// Checks `actual` against a crate's snapshot, or records it when
+/// `update` is set.
+pub fn check(crate_dir: &str, name: &str, actual: &str, update: bool) -> Result<(), String> {
+    let path = path(crate_dir, name);
+    if update {
+        fs::create_dir_all(path.parent().unwrap()).map_err(|e| e.to_string())?;
+        return fs::write(&path, actual).map_err(|e| format!("Unable to write {}: {e}", path.display()));
+    }
+    let Ok(expected) = fs::read_to_string(&path) else {
+        return Err(format!("No snapshot at {}, run with {UPDATE}=1 to record it", path.display()));
+    };
+    if expected == actual {
+        Ok(())
+    } else if expected.lines().eq(actual.lines()) {
+        Err(format!("Snapshot {name} differs only in line endings, run with {UPDATE}=1 to accept it"))
+    } else {
+        Err(format!("Snapshot {name} changed, run with {UPDATE}=1 to accept it:\n{}", diff(&expected, actual)))
+    }
+}
+
+/// Whether snapshots are being accepted rather than checked.
+pub fn updating() -> bool {
+    env::var(UPDATE).is_ok_and(|value| value == "1")
+}
+
+/// Asserts that text matches the snapshot `name` of the calling crate.
+///
+/// ```no_run
+/// snapshot::assert_snapshot!("greeting", format!("hello {}", "world"));
+/// ```
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($name:expr, $actual:expr) => {
+        if let Err(message) = $crate::check(env!("CARGO_MANIFEST_DIR"), $name, &$actual, $crate::updating()) {
+            panic!("{}", message);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(diff("a\nb\nc\n", "a\nc\nd\n"), " a\n-b\n c\n+d\n");
+        assert_eq!(diff("", "a\n"), "+a\n");
+        assert_eq!(diff("same\n", "same\n"), " same\n");
+    }
+
+    #[test]
+    fn test_check() {
+        let dir = env::temp_dir().join(format!("snapshot-test-{}", std::process::id()));
+        let dir = dir.to_str().unwrap();
+        assert!(check(dir, "grid", "|a b|\n", false).unwrap_err().starts_with("No snapshot"));
+        check(dir, "grid", "|a b|\n", true).unwrap();
+        assert_eq!(check(dir, "grid", "|a b|\n", false), Ok(()));
+        let changed = check(dir, "grid", "|a c|\n", false).unwrap_err();
+        assert!(changed.ends_with("-|a b|\n+|a c|\n"), "{changed}");
+        assert!(check(dir, "grid", "|a b|\r\n", false).unwrap_err().contains("line endings"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_assert_snapshot() {
+        assert_snapshot!("example", String::from("golden\n"));
+    }
+}
@@ -0,0 +1,182 @@
+//! Cooperative cancellation and progress reporting for solvers.
+//!
+//! Long running solvers say what they are doing with [`stage`], and call
+//! [`progress`] or [`checkpoint`] as they go. A caller runs them under a
+//! [`Watch`], which can follow their progress and cancel them from another
+//! thread: the solver's next checkpoint unwinds back out of [`Watch::run`].
+//! Outside of a watch these calls do nothing.
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// How far a watched solver has got.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Progress {
+    /// The stage the solver last entered, if any.
+    pub stage: Option<String>,
+    /// Work done so far in this stage.
+    pub done: u64,
+    /// Work the stage has in total, when the solver knows.
+    pub total: Option<u64>,
+}
+
+/// A watched solver stopped at a checkpoint after being cancelled.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cancelled {
+    /// The stage it was in.
+    pub stage: Option<String>,
+}
+
+// `total` when the solver hasn't said.
+const UNKNOWN: u64 = u64::MAX;
+
+struct State {
+    cancelled: AtomicBool,
+    stage: Mutex<Option<String>>,
+    done: AtomicU64,
+    total: AtomicU64,
+}
+
+/// Watches a solver, shared between the solver's thread and its caller.
+#[derive(Clone)]
+pub struct Watch {
+    state: Arc<State>,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Arc<State>>> = const { RefCell::new(None) };
+}
+
+fn with_state<R>(f: impl FnOnce(&State) -> R) -> Option<R> {
+    CURRENT.with(|current| current.borrow().as_deref().map(f))
+}
+
+impl Default for Watch {
+    fn default() -> Self {
+        Watch::new()
+    }
+}
+
+impl Watch {
+    pub fn new() -> Watch {
+        Watch {
+            state: Arc::new(State {
+                cancelled: AtomicBool::new(false),
+                stage: Mutex::new(None),
+                done: AtomicU64::new(0),
+                total: AtomicU64::new(UNKNOWN),
+            })
+        }
+    }
+
+    /// Asks the solver to stop at its next checkpoint.
+    pub fn cancel(&self) {
+        self.state.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn progress(&self) -> Progress {
+        let total = self.state.total.load(Ordering::Relaxed);
+        Progress {
+            stage: self.state.stage.lock().unwrap().clone(),
+            done: self.state.done.load(Ordering::Relaxed),
+            total: (total != UNKNOWN).then_some(total),
+        }
+    }
+
+    /// Runs `f` on this thread under the watch, until it returns or is
+    /// cancelled.
+    pub fn run<R>(&self, f: impl FnOnce() -> R) -> Result<R, Cancelled> {
+        let outer = CURRENT.with(|current| current.replace(Some(self.state.clone())));
+        let result = panic::catch_unwind(AssertUnwindSafe(f));
+        CURRENT.with(|current| current.replace(outer));
+        match result {
+            Ok(result) => Ok(result),
+            Err(payload) => match payload.downcast::<Cancelled>() {
+                Ok(cancelled) => Err(*cancelled),
+                // A real panic, which isn't ours to handle.
+                Err(payload) => panic::resume_unwind(payload),
+            },
+        }
+    }
+}
+
+/// Enters a new stage of solving, with no progress yet.
+pub fn stage(name: &str) {
+    with_state(|state| {
+        *state.stage.lock().unwrap() = Some(String::from(name));
+        state.done.store(0, Ordering::Relaxed);
+        state.total.store(UNKNOWN, Ordering::Relaxed);
+    });
+    checkpoint();
+}
+
+/// Reports how much of the current stage is done, then checks in.
+pub fn progress(done: u64, total: Option<u64>) {
+    with_state(|state| {
+        state.done.store(done, Ordering::Relaxed);
+        state.total.store(total.unwrap_or(UNKNOWN), Ordering::Relaxed);
+    });
+    checkpoint();
+}
+
+/// Stops the solver here if its watch was cancelled.
+pub fn checkpoint() {
+    let cancelled = with_state(|state| {
+        state.cancelled.load(Ordering::Relaxed).then(|| state.stage.lock().unwrap().clone())
+    }).flatten();
+    if let Some(stage) = cancelled {
+        // Not a panic, so no panic message is printed on the way out.
+        panic::resume_unwind(Box::new(Cancelled { stage }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::sync::mpsc::channel;
+    use std::thread;
+
+    #[test]
+    fn test_unwatched() {
+        stage("anything");
+        progress(1, Some(2));
+        checkpoint();
+        assert_eq!(Watch::new().run(|| 42), Ok(42));
+    }
+
+    #[test]
+    fn test_progress() {
+        let watch = Watch::new();
+        let progress = watch.run(|| {
+            stage("counting");
+            progress(3, Some(10));
+            watch.progress()
+        });
+        assert_eq!(progress, Ok(Progress { stage: Some(String::from("counting")), done: 3, total: Some(10) }));
+    }
+
+    #[test]
+    fn test_cancel() {
+        let watch = Watch::new();
+        let (started, wait) = channel();
+        let solver = watch.clone();
+        let handle = thread::spawn(move || solver.run(|| {
+            stage("forever");
+            started.send(()).unwrap();
+            loop {
+                checkpoint();
+            }
+        }));
+        wait.recv().unwrap();
+        watch.cancel();
+        let result: Result<(), Cancelled> = handle.join().unwrap();
+        assert_eq!(result, Err(Cancelled { stage: Some(String::from("forever")) }));
+    }
+
+    #[test]
+    fn test_panics_pass_through() {
+        let result = panic::catch_unwind(|| Watch::new().run(|| panic!("bad input")));
+        assert!(result.is_err());
+    }
+}